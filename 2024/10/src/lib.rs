@@ -0,0 +1,63 @@
+use aoc_base::Answer;
+use aoc_base::Coord;
+use aoc_base::Grid;
+use aoc_base::ParseResult;
+use aoc_base::Solution;
+
+// Define directions of movement
+const DIRECTIONS: [Coord; 4] = [Coord::from_rc(0, 1), Coord::from_rc(1, 0), Coord::from_rc(0, -1), Coord::from_rc(-1, 0)];
+
+// Recursive function to find the trail
+fn trail(grid: &Grid<i8>, pos: Coord, trail_ends: &mut Vec<Coord>) {
+    let pos_value = grid.at(&pos).unwrap();
+
+    if pos_value == 9 {
+        trail_ends.push(pos);
+        return;
+    }
+
+    for dir in DIRECTIONS.iter() {
+        let new_pos = pos + *dir;
+        if grid.valid_coords(&new_pos) && grid.at(&new_pos).unwrap() == pos_value + 1 {
+            trail(grid, new_pos, trail_ends);
+        }
+    }
+}
+
+// Sum scores (unique trail ends) and ratings (all trails) of every trailhead
+fn trail_stats(grid: &Grid<i8>) -> (usize, usize) {
+    let trailheads = grid.find(0);
+    let mut trail_scores = 0;
+    let mut trail_ratings = 0;
+
+    for trailhead in trailheads.iter() {
+        let mut current_trail_ends: Vec<Coord> = Vec::new();
+        trail(grid, *trailhead, &mut current_trail_ends);
+        trail_ratings += current_trail_ends.len();
+        // Sort and remove duplicates for the current trail
+        current_trail_ends.sort_unstable();
+        current_trail_ends.dedup();
+        trail_scores += current_trail_ends.len();
+    }
+
+    (trail_scores, trail_ratings)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Grid<i8>;
+    type Params = ();
+
+    fn parse(input: &str, _params: &Self::Params) -> ParseResult<Self::Input> {
+        Ok(Grid::try_from_str(input)?)
+    }
+
+    fn part1(grid: &Self::Input) -> Answer {
+        trail_stats(grid).0.into()
+    }
+
+    fn part2(grid: &Self::Input) -> Answer {
+        trail_stats(grid).1.into()
+    }
+}
@@ -1,17 +1,23 @@
-use std::fs::File;
-use std::io::Read;
 use std::collections::HashMap;
 use std::collections::HashSet;
+use aoc_base::parse::lines;
+use aoc_base::parse::ParseError;
+use aoc_base::parse_param;
+use aoc_base::Answer;
+use aoc_base::Params;
+use aoc_base::ParseResult;
+use aoc_base::Solution;
 
 type Stone = u64;
 type StoneCnt = u64;
 
-// Read stones from file
-fn read_stones(filename: &str) -> Vec<Stone> {
-    let mut file = File::open(filename).unwrap();
-    let mut text = String::new();
-    file.read_to_string(&mut text).unwrap();
-    text.split_whitespace().map(|line| line.parse().unwrap()).collect()
+// Read stones from text
+fn read_stones(text: &str) -> Result<Vec<Stone>, ParseError> {
+    let mut stones = Vec::new();
+    for line in lines(text) {
+        stones.extend(line.ints::<Stone>()?);
+    }
+    Ok(stones)
 }
 
 // Blink a single stone as per defined rules and return new sequence of stones
@@ -22,7 +28,7 @@ fn blink_stone(stone: Stone, stones: &mut Vec<Stone>) {
     } 
     let stone_str = stone.to_string();
     let stone_str_len = stone_str.len();
-    if stone_str_len % 2 == 0 {
+    if stone_str_len.is_multiple_of(2) {
         let halves = stone_str.split_at(stone_str_len / 2);
         stones.push(halves.0.parse().unwrap());
         stones.push(halves.1.parse().unwrap());
@@ -32,7 +38,7 @@ fn blink_stone(stone: Stone, stones: &mut Vec<Stone>) {
 }
 
 // Blink with a sequence of stones returning new sequence of items
-fn blink_stones(stones: &Vec<Stone>) -> Vec<Stone> {
+fn blink_stones(stones: &[Stone]) -> Vec<Stone> {
     let mut new_stones = Vec::new();
     stones.iter().for_each(|&stone| blink_stone(stone, &mut new_stones));
     new_stones
@@ -47,8 +53,8 @@ fn blink_stone_n_cnt(stone: Stone, n: u8) -> StoneCnt {
     new_stones.len() as u64
 }
 
-// Fill stone map with stone sequence after `n` iterations and number of stones after `m` iterations (m > n) 
-fn fill_map(stones: &Vec<Stone>, map: &mut HashMap<Stone, (StoneCnt, Vec<Stone>)>, n: u8, m: u8) {
+// Fill stone map with stone sequence after `n` iterations and number of stones after `m` iterations
+fn fill_map(stones: &[Stone], map: &mut HashMap<Stone, (StoneCnt, Vec<Stone>)>, n: u8, m: u8) {
     let mut new_stones = Vec::new();
     for s in stones.iter() {
         if map.contains_key(s) {
@@ -57,28 +63,34 @@ fn fill_map(stones: &Vec<Stone>, map: &mut HashMap<Stone, (StoneCnt, Vec<Stone>)
         new_stones.clear();
         new_stones.push(*s);
         let mut stone_sequence = Vec::new();
-        for i in 0..m {
+        let mut stone_count = 0;
+        // Blink until both the sequence and the count are known, either may come first
+        for i in 0..n.max(m) {
             new_stones = blink_stones(&new_stones);
             if i == n - 1 {
                 stone_sequence = new_stones.clone();
             } 
+            if i == m - 1 {
+                stone_count = new_stones.len() as StoneCnt;
+            }
         }
-        map.insert(*s, (new_stones.len() as StoneCnt, stone_sequence));        
+        map.insert(*s, (stone_count, stone_sequence));        
     }
 }
 
-fn main() {
-    let mut stone_map: HashMap<Stone, (StoneCnt, Vec<Stone>)> = HashMap::new();
-    let mut stones: Vec<Stone> = read_stones("input");
-    let blinks_part1 = 25;
-    let blinks_part2 = 75;
-
-    // Solution 1
-    let mut stones_part1 = stones.clone();
-    for _ in 0..blinks_part1 {
-        stones_part1 = blink_stones(&stones_part1)
+// Blink stones `blinks` times one by one
+fn blink_simple(stones: &[Stone], blinks: u8) -> StoneCnt {
+    let mut stones = stones.to_vec();
+    for _ in 0..blinks {
+        stones = blink_stones(&stones)
     }
-    println!("Part 1: {}", stones_part1.len());
+    stones.len() as StoneCnt
+}
+
+// Blink stones `blinks` times using cached sequences
+fn blink_cached(stones: &[Stone], blinks: u8) -> StoneCnt {
+    let mut stone_map: HashMap<Stone, (StoneCnt, Vec<Stone>)> = HashMap::new();
+    let mut stones = stones.to_vec();
 
     /* Solution 2
     Separate iterations into 3 stages:
@@ -89,7 +101,11 @@ fn main() {
     */
     let blinks_first_stage = 30; 
     let blinks_second_stage = 20;
-    let blinks_third_stage = blinks_part2 - blinks_first_stage - blinks_second_stage;
+    // Few blinks are fast enough without caching
+    if blinks <= blinks_first_stage + blinks_second_stage {
+        return blink_simple(&stones, blinks);
+    }
+    let blinks_third_stage = blinks - blinks_first_stage - blinks_second_stage;
 
     // First stage
     for _ in 0..blinks_first_stage {
@@ -136,6 +152,54 @@ fn main() {
         sum += s_count * s_sum;
     }
 
-    println!("Part 2: {}", sum);
+    sum
+}
+
+// Number of blinks in each part
+pub struct BlinkParams {
+    pub blinks1: u8,
+    pub blinks2: u8,
+}
+
+impl Default for BlinkParams {
+    fn default() -> Self {
+        BlinkParams { blinks1: 25, blinks2: 75 }
+    }
+}
+
+impl Params for BlinkParams {
+    fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "blinks1" => self.blinks1 = parse_param(key, value)?,
+            "blinks2" => self.blinks2 = parse_param(key, value)?,
+            _ => return Err(format!("unknown parameter {:?}", key)),
+        }
+        Ok(())
+    }
+}
+
+// Stones in a line and how many times to blink at them
+pub struct Stones {
+    stones: Vec<Stone>,
+    blinks1: u8,
+    blinks2: u8,
+}
+
+pub struct Day11;
 
+impl Solution for Day11 {
+    type Input = Stones;
+    type Params = BlinkParams;
+
+    fn parse(input: &str, params: &Self::Params) -> ParseResult<Self::Input> {
+        Ok(Stones { stones: read_stones(input)?, blinks1: params.blinks1, blinks2: params.blinks2 })
+    }
+
+    fn part1(stones: &Self::Input) -> Answer {
+        blink_simple(&stones.stones, stones.blinks1).into()
+    }
+
+    fn part2(stones: &Self::Input) -> Answer {
+        blink_cached(&stones.stones, stones.blinks2).into()
+    }
 }
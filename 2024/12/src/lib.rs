@@ -0,0 +1,37 @@
+use aoc_base::Answer;
+use aoc_base::Grid;
+use aoc_base::ParseResult;
+use aoc_base::Solution;
+
+// Calculate fence costs using simple perimeter (first) and number of sides (second)
+fn fence_costs(grid: &Grid<char>) -> (u32, u32) {
+    let mut cost1 = 0;
+    let mut cost2 = 0;
+    // Connected regions of the same plant
+    for region in grid.components(|a, b| a == b).iter() {
+        let area = region.area() as u32;
+        cost1 += region.perimeter() as u32 * area;
+        cost2 += region.sides() as u32 * area;
+    }
+
+    (cost1, cost2)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Grid<char>;
+    type Params = ();
+
+    fn parse(input: &str, _params: &Self::Params) -> ParseResult<Self::Input> {
+        Ok(Grid::try_from_str(input)?)
+    }
+
+    fn part1(grid: &Self::Input) -> Answer {
+        fence_costs(grid).0.into()
+    }
+
+    fn part2(grid: &Self::Input) -> Answer {
+        fence_costs(grid).1.into()
+    }
+}
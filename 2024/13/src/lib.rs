@@ -0,0 +1,97 @@
+extern crate nalgebra as na;
+use na::Matrix2;
+use aoc_base::parse::blocks;
+use aoc_base::parse::ints_n;
+use aoc_base::parse::ParseError;
+use aoc_base::parse_param;
+use aoc_base::Answer;
+use aoc_base::Params;
+use aoc_base::ParseResult;
+use aoc_base::Solution;
+
+// Button movements as columns of a matrix and prize location
+type Machine = (Matrix2<f64>, [f64; 2]);
+
+// Every machine is a block with movements of both buttons and the prize location
+fn parse_input(input: &str) -> Result<Vec<Machine>, ParseError> {
+    blocks(input).iter().map(|block| {
+        let [ax, ay, bx, by, x, y] = block.context(ints_n::<f64, 6>(block.text))?;
+        Ok((Matrix2::new(ax, bx, ay, by), [x, y]))
+    }).collect()
+}
+
+fn cramer(matrix: Matrix2<f64>, price: [f64; 2]) -> Option<[f64; 2]> {
+    let det = matrix.determinant();
+    let mut result = [0.0, 0.0];
+    for i in 0..2 {
+        let mut new_matrix = matrix;
+        new_matrix[(0, i)] = price[0];
+        new_matrix[(1, i)] = price[1];
+        result[i] = new_matrix.determinant() / det;
+    }
+
+    // Check if result is close enough to integer
+    if result.iter().all(|&x| (x - x.round()).abs() < 0.0001) {
+        Some(result)
+    } else {
+        None
+    }
+}
+
+// Count tokens needed to win all possible prizes, prizes are shifted by `offset`
+fn tokens(machines: &[Machine], offset: f64) -> i64 {
+    let mut sum = 0;
+    for eq in machines.iter() {
+        let price = [eq.1[0] + offset, eq.1[1] + offset];
+        if let Some(result) = cramer(eq.0, price) {
+            sum += result[0] as i64 * 3 + result[1] as i64;
+        }
+    }
+    sum
+}
+
+// Shift of the prizes in the second part
+pub struct PrizeParams {
+    pub offset: u64,
+}
+
+impl Default for PrizeParams {
+    fn default() -> Self {
+        PrizeParams { offset: 10000000000000 }
+    }
+}
+
+impl Params for PrizeParams {
+    fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "offset" => self.offset = parse_param(key, value)?,
+            _ => return Err(format!("unknown parameter {:?}", key)),
+        }
+        Ok(())
+    }
+}
+
+// Claw machines with the prize shift of the second part
+pub struct Arcade {
+    machines: Vec<Machine>,
+    offset: u64,
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Arcade;
+    type Params = PrizeParams;
+
+    fn parse(input: &str, params: &Self::Params) -> ParseResult<Self::Input> {
+        Ok(Arcade { machines: parse_input(input)?, offset: params.offset })
+    }
+
+    fn part1(arcade: &Self::Input) -> Answer {
+        tokens(&arcade.machines, 0.0).into()
+    }
+
+    fn part2(arcade: &Self::Input) -> Answer {
+        tokens(&arcade.machines, arcade.offset as f64).into()
+    }
+}
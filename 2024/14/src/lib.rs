@@ -0,0 +1,138 @@
+use aoc_base::parse::ints_n;
+use aoc_base::parse::lines;
+use aoc_base::parse::ParseError;
+use aoc_base::parse_param;
+use aoc_base::Answer;
+use aoc_base::Grid;
+use aoc_base::Coord;
+use aoc_base::Params;
+use aoc_base::ParseResult;
+use aoc_base::Solution;
+use aoc_base::Topology;
+
+// Read robots as `p=x,y v=x,y` lines
+fn parse_input(input: &str) -> Result<Vec<(Coord, Coord)>, ParseError> {
+    lines(input).map(|line| {
+        let [px, py] = line.context(ints_n(line.kv("p=")?))?;
+        let [vx, vy] = line.context(ints_n(line.kv("v=")?))?;
+        Ok((Coord::from_xy(px, py), Coord::from_xy(vx, vy)))
+    }).collect()
+}
+
+// Check if no bots overlap
+fn no_overlap(robots: &[(Coord, Coord)]) -> bool {
+    let mut set = std::collections::HashSet::new();
+    for robot in robots.iter() {
+        if !set.insert(robot.0) {
+            return false;
+        }
+    }
+    true
+}
+
+// Simulate the robots moving, the room wraps around so all steps can be done at once
+fn simulate(robots: &mut [(Coord, Coord)], grid: &Grid<u32>, steps: i32) {
+    for robot in robots.iter_mut() {
+        robot.0 = grid.move_by(&robot.0, robot.1, steps).unwrap();
+    }
+}
+
+// Calculate the number of robots in each quadrant
+fn quadrants(robots: &[(Coord, Coord)], grid: &Grid<u32>) -> (i32, i32, i32, i32) {
+    let horizontal = grid.width / 2; 
+    let vertical = grid.height / 2;
+    let mut q = (0, 0, 0, 0);
+    for robot in robots.iter() {
+        if robot.0.col < horizontal {
+            if robot.0.row < vertical {
+                q.0 += 1;
+            } else if robot.0.row > vertical {
+                q.1 += 1;
+            }
+        } else if robot.0.col > horizontal {
+            if robot.0.row < vertical {
+                q.2 += 1;
+            } else if robot.0.row > vertical {
+                q.3 += 1;
+            }
+        }
+    }
+    q
+}
+
+// Size of the room and number of steps simulated in the first part
+pub struct RoomParams {
+    pub width: u32,
+    pub height: u32,
+    pub steps: i32,
+}
+
+impl Default for RoomParams {
+    fn default() -> Self {
+        RoomParams { width: 101, height: 103, steps: 100 }
+    }
+}
+
+impl Params for RoomParams {
+    fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "width" => self.width = parse_param(key, value)?,
+            "height" => self.height = parse_param(key, value)?,
+            "steps" => self.steps = parse_param(key, value)?,
+            _ => return Err(format!("unknown parameter {:?}", key)),
+        }
+        if self.width == 0 || self.height == 0 {
+            return Err(format!("room has to be at least 1x1, got {}x{}", self.width, self.height));
+        }
+        Ok(())
+    }
+}
+
+// Robots (position and velocity) in a room of a given size
+pub struct Room {
+    robots: Vec<(Coord, Coord)>,
+    width: u32,
+    height: u32,
+    steps: i32,
+}
+
+impl Room {
+    // Empty grid of the room, robots leaving on one side enter on the other
+    fn grid(&self) -> Grid<u32> {
+        Grid::filled(self.width, self.height, 0).with_topology(Topology::Torus)
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Room;
+    type Params = RoomParams;
+
+    fn parse(input: &str, params: &Self::Params) -> ParseResult<Self::Input> {
+        Ok(Room { robots: parse_input(input)?, width: params.width, height: params.height, steps: params.steps })
+    }
+
+    fn part1(room: &Self::Input) -> Answer {
+        let mut robots = room.robots.clone();
+        let grid = room.grid();
+
+        simulate(&mut robots, &grid, room.steps);
+        let q = quadrants(&robots, &grid);
+        (q.0 * q.1 * q.2 * q.3).into()
+    }
+
+    fn part2(room: &Self::Input) -> Answer {
+        let mut robots = room.robots.clone();
+        let grid = room.grid();
+
+        // The tree is drawn the first time no robots overlap
+        for i in 1..10000 {
+            simulate(&mut robots, &grid, 1);
+            if no_overlap(&robots) {
+                return i.into();
+            }
+        }
+        "not found".into()
+    }
+}
@@ -0,0 +1,290 @@
+use std::fmt;
+use std::fmt::Display;
+use aoc_base::parse::blocks_n;
+use aoc_base::parse::ParseError;
+use aoc_base::Answer;
+use aoc_base::Grid;
+use aoc_base::Direction;
+use aoc_base::Coord;
+use aoc_base::ParseResult;
+use aoc_base::Solution;
+use aoc_base::SparseGrid;
+
+// Walls ('#') and boxes ('O') of the warehouse
+struct GameState {
+    robot: Coord,
+    map: SparseGrid<char>,
+}
+
+impl Display for GameState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut map = self.map.clone();
+        map.set(self.robot, '@');
+        write!(f, "{}", map)
+    }
+}
+
+// Walls ('#') and wide boxes ('[' and ']') of the warehouse
+struct GameState2 {
+    robot: Coord,
+    map: SparseGrid<char>,
+}
+
+impl Display for GameState2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut map = self.map.clone();
+        map.set(self.robot, '@');
+        write!(f, "{}", map)
+    }
+}
+
+impl GameState2 {
+    fn is_wall(&self, pos: Coord) -> bool {
+        self.map.at(&pos) == Some('#')
+    }
+
+    // Get both halves of the box at a position
+    fn contains_box(&self, pos: Coord) -> Option<(Coord, Coord)> {
+        match self.map.at(&pos) {
+            Some('[') => Some((pos, pos + Coord::from_rc(0, 1))),
+            Some(']') => Some((pos - Coord::from_rc(0, 1), pos)),
+            _ => None,
+        }
+    }
+
+    fn remove_box(&mut self, b: &(Coord, Coord)) {
+        self.map.remove(&b.0);
+        self.map.remove(&b.1);
+    }
+
+    fn add_box(&mut self, b: (Coord, Coord)) {
+        self.map.set(b.0, '[');
+        self.map.set(b.1, ']');
+    }
+}
+
+// Collect the given kinds of cells of the grid into a sparse map
+fn warehouse_map(grid: &Grid<char>, kinds: &[char]) -> SparseGrid<char> {
+    kinds.iter().flat_map(|&k| grid.find(k).into_iter().map(move |pos| (pos, k))).collect()
+}
+
+// Parse text into grid and list of moves
+fn parse_input(text: &str) -> ParseResult<(Grid<char>, Vec<Direction>)> {
+    let [map, directions] = blocks_n(text)?;
+    let grid = Grid::try_from_str(map.text)?;
+    let mut moves = Vec::new();
+    for line in directions.lines() {
+        for c in line.text.chars() {
+            moves.push(line.context(Direction::try_from(c))?);
+        }
+    }
+    Ok((grid, moves))
+}
+
+// Robot wants to move in direction but there are (one or more) boxes in the way, 
+// check if you can move them and if so, move the boxes and return true, otherwise return false
+fn move_boxes(game: &mut GameState, pos: Coord, direction: &Direction) -> bool {
+    let dir = direction.to_coord();
+    let mut last = pos;
+    loop {
+        let new_pos = last + dir;
+        match game.map.at(&new_pos) {
+            Some('#') => return false,
+            Some('O') => last = new_pos,
+            _ => {
+                // Move the first box in the sequence to the new position
+                game.map.remove(&pos);
+                game.map.set(new_pos, 'O');
+                return true;
+            }
+        }
+    }
+}
+
+// Move robot within the grid in the given direction
+fn robot_move(game: &mut GameState, direction: &Direction) {
+    let new_pos = game.robot + direction.to_coord();
+    
+    if game.map.at(&new_pos) == Some('#') {
+        return;
+    }
+    
+    if game.map.at(&new_pos) == Some('O') {
+        if move_boxes(game, new_pos, direction) {
+            game.robot = new_pos;
+        }
+    } else {
+        game.robot = new_pos;
+    }
+}
+
+// Find all boxes connected to the start box in the given vertical direction, if no boxes can be moved return None
+fn connected_boxes(game: &GameState2, start_box: (Coord, Coord), direction: &Direction) -> Option<Vec<(Coord, Coord)>> {
+    let mut boxes = vec![start_box];
+    let mut connected_boxes = Vec::new();
+    let movement = if *direction == Direction::Up { -1 } else { 1 };
+
+    while !boxes.is_empty() {
+        let b = boxes[0];
+        let left_pos = b.0 + Coord::from_rc(movement, 0);
+        let right_pos = b.1 + Coord::from_rc(movement, 0);
+        if game.is_wall(left_pos) || game.is_wall(right_pos) {
+            return None;
+        }
+        if game.contains_box(left_pos).is_some() {
+            boxes.push(game.contains_box(left_pos).unwrap());
+        }
+        if game.contains_box(right_pos).is_some() {
+            boxes.push(game.contains_box(right_pos).unwrap());
+        }
+        boxes.retain(|&x| x != b);
+        connected_boxes.push(b);
+    }
+    
+    Some(connected_boxes)
+} 
+
+// Move boxes in the wide grid in the given direction
+fn move_boxes_wide(game: &mut GameState2, start_box: (Coord, Coord), direction: &Direction) -> bool {
+    // Handle horizontal movement
+    if *direction == Direction::Left || *direction == Direction::Right {
+        let mut box_sequence = vec![start_box];
+        let next_field = if *direction == Direction::Left { -1 } else { 2 };
+        loop {
+            // Get the next possible field
+            let new_pos = box_sequence.last().unwrap().0 + Coord::from_rc(0, next_field);
+            if game.is_wall(new_pos) {
+                return false;
+            } else if let Some(b) = game.contains_box(new_pos) {
+                box_sequence.push(b);
+            } else {
+                // Remove all boxes first so that moved boxes are not overwritten
+                for b in box_sequence.iter() {
+                    game.remove_box(b);
+                }
+                // Move boxes to the new position
+                let box_offset = if *direction == Direction::Left { -1 } else { 1 };
+                for b in box_sequence.iter() {
+                    let offset = Coord::from_rc(0, box_offset);
+                    game.add_box((b.0 + offset, b.1 + offset));
+                }
+                return true;
+            }
+        }
+    }
+
+    // Handle vertical movement
+    let connected_boxes = connected_boxes(game, start_box, direction).unwrap_or(vec![]);
+
+    if connected_boxes.is_empty() {
+        return false;
+    }
+
+    // Remove old boxes - this can't be combined with moving the boxes as new boxes might get deleted
+    for b in &connected_boxes {
+        game.remove_box(b);
+    }
+
+    for b in &connected_boxes {
+        let box_offset = if *direction == Direction::Up { -1 } else { 1 };
+        let offset = Coord::from_rc(box_offset, 0);
+        game.add_box((b.0 + offset, b.1 + offset));
+    }
+
+    true
+}
+
+// Move robot within the wide grid in the given direction
+fn robot_move_wide(game: &mut GameState2, direction: &Direction) {
+    let new_pos = game.robot + direction.to_coord();
+    
+    if game.is_wall(new_pos) {
+        return;
+    }
+    
+    if let Some(possible_box) = game.contains_box(new_pos) {
+        if move_boxes_wide(game, possible_box, direction) {
+            game.robot = new_pos;
+        }
+    } else {
+        game.robot = new_pos;
+    }
+}
+
+// GPS sum of boxes coordinates (left halves of wide boxes)
+fn gpsum(boxes: &[Coord]) -> u32 {
+    let mut sum = 0;
+    for b in boxes.iter() {
+        sum += (b.row * 100 + b.col) as u32;
+    }
+    sum
+}
+
+// Widen the grid by duplicating each cell horizontally
+fn grid_widen(grid: &Grid<char>) -> Grid<char> {
+    grid.scale_x(2, |cell| match cell {
+        '#' => ['#', '#'],
+        'O' => ['[', ']'],
+        '@' => ['@', '.'],
+        _ => ['.', '.'],
+    })
+}
+
+// Warehouse map and robot moves
+pub struct Warehouse {
+    grid: Grid<char>,
+    robot: Coord,
+    moves: Vec<Direction>,
+}
+
+// Run all moves in the original warehouse and get GPS sum of boxes
+fn run_narrow(grid: &Grid<char>, robot: Coord, moves: &[Direction]) -> u32 {
+    let mut game_state = GameState {
+        robot,
+        map: warehouse_map(grid, &['#', 'O']),
+    };
+
+    for m in moves.iter() {
+        robot_move(&mut game_state, m);
+        // println!("{}", game_state);
+    }
+
+    gpsum(&game_state.map.find('O'))
+}
+
+// Run all moves in the widened warehouse and get GPS sum of boxes
+fn run_wide(grid: &Grid<char>, robot: Coord, moves: &[Direction]) -> u32 {
+    let grid2 = grid_widen(grid);
+    let mut game_state2 = GameState2 {
+        robot: Coord::from_rc(robot.row, robot.col * 2),
+        map: warehouse_map(&grid2, &['#', '[', ']']),
+    };
+
+    for m in moves.iter() {
+        robot_move_wide(&mut game_state2, m);
+        // println!("{}", game_state2);
+    }
+
+    gpsum(&game_state2.map.find('['))
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Warehouse;
+    type Params = ();
+
+    fn parse(input: &str, _params: &Self::Params) -> ParseResult<Self::Input> {
+        let (grid, moves) = parse_input(input)?;
+        let robot = grid.find_first('@').ok_or(ParseError::new("warehouse has no robot '@'"))?;
+        Ok(Warehouse { grid, robot, moves })
+    }
+
+    fn part1(warehouse: &Self::Input) -> Answer {
+        run_narrow(&warehouse.grid, warehouse.robot, &warehouse.moves).into()
+    }
+
+    fn part2(warehouse: &Self::Input) -> Answer {
+        run_wide(&warehouse.grid, warehouse.robot, &warehouse.moves).into()
+    }
+}
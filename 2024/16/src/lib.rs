@@ -0,0 +1,106 @@
+use aoc_base::all_directions;
+use aoc_base::pathfinding::dijkstra_all;
+use aoc_base::parse::ParseError;
+use aoc_base::Answer;
+use aoc_base::Grid;
+use aoc_base::Direction;
+use aoc_base::Coord;
+use aoc_base::ParseResult;
+use aoc_base::Solution;
+
+use std::collections::HashSet;
+
+type Weight = u32;
+type Node = (Coord, Direction);
+
+// Moves of the reindeer: one step forward costs 1, turning by 90 degrees costs 1000
+fn moves(grid: &Grid<char>, (pos, dir): &Node) -> Vec<(Node, Weight)> {
+    let mut result = Vec::new();
+    let next = *pos + dir.to_coord();
+    if grid.at(&next).is_some_and(|c| c != '#') {
+        result.push(((next, *dir), 1));
+    }
+    for turn in [dir.turn_left(), dir.turn_right()] {
+        result.push(((*pos, turn), 1000));
+    }
+    result
+}
+
+// Maze with the start and end tiles of the reindeer
+pub struct Maze {
+    grid: Grid<char>,
+    start: Coord,
+    end: Coord,
+}
+
+// Find the lowest score from start to end (first) and number of tiles on any best path (second)
+fn best_paths(maze: &Maze) -> (Weight, usize) {
+    let Maze { grid, start, end } = maze;
+
+    let dag = dijkstra_all((*start, Direction::Right), |n| moves(grid, n));
+    let ends: Vec<Node> = all_directions().into_iter().map(|d| (*end, d)).collect();
+    let best = ends.iter().filter_map(|n| dag.distance(n)).min().unwrap();
+
+    let tiles: HashSet<Coord> = dag.nodes_on_any_optimal_path(&ends).into_iter().map(|(pos, _)| pos).collect();
+    (best, tiles.len())
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Maze;
+    type Params = ();
+
+    fn parse(input: &str, _params: &Self::Params) -> ParseResult<Self::Input> {
+        let grid = Grid::try_from_str(input)?;
+        let start = grid.find_first('S').ok_or(ParseError::new("maze has no start 'S'"))?;
+        let end = grid.find_first('E').ok_or(ParseError::new("maze has no end 'E'"))?;
+        Ok(Maze { grid, start, end })
+    }
+
+    fn part1(maze: &Self::Input) -> Answer {
+        best_paths(maze).0.into()
+    }
+
+    fn part2(maze: &Self::Input) -> Answer {
+        best_paths(maze).1.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = include_str!("../example1");
+    const EXAMPLE2: &str = include_str!("../example2");
+
+    fn dag(text: &str) -> (aoc_base::pathfinding::PathDag<Node, Weight>, Vec<Node>) {
+        let maze = Day16::parse(text, &()).unwrap();
+        let dag = dijkstra_all((maze.start, Direction::Right), |n| moves(&maze.grid, n));
+        (dag, all_directions().into_iter().map(|d| (maze.end, d)).collect())
+    }
+
+    #[test]
+    fn optimal_paths() {
+        for (text, best, count) in [(EXAMPLE1, 7036, 3), (EXAMPLE2, 11048, 2)] {
+            let (dag, ends) = dag(text);
+            assert_eq!(dag.count_optimal_paths(&ends), count);
+            let paths = dag.enumerate_optimal_paths(&ends, usize::MAX);
+            assert_eq!(paths.len() as u64, count);
+
+            // Every enumerated path is a different path with the best score
+            for path in paths.iter() {
+                assert_eq!(path[0], *dag.start());
+                assert_eq!(dag.distance(path.last().unwrap()), Some(best));
+            }
+            let unique: HashSet<&Vec<Node>> = paths.iter().collect();
+            assert_eq!(unique.len(), paths.len());
+
+            // Nodes on optimal paths are exactly the nodes of the enumerated paths
+            let on_paths: HashSet<Node> = paths.into_iter().flatten().collect();
+            assert_eq!(on_paths, dag.nodes_on_any_optimal_path(&ends));
+
+            assert_eq!(dag.enumerate_optimal_paths(&ends, 1).len(), 1);
+        }
+    }
+}
@@ -1,11 +1,16 @@
-use std::fs::File;
-use std::io::Read;
+use aoc_base::parse::blocks_n;
+use aoc_base::parse::ints;
+use aoc_base::parse::ints_n;
+use aoc_base::parse::ParseError;
+use aoc_base::Answer;
+use aoc_base::ParseResult;
+use aoc_base::Solution;
 
 // Instruction holds opcode and operand
 type Instruction = (u8, u64);
 
-#[derive(Debug)]
-struct Processor {
+#[derive(Debug, Clone)]
+pub struct Processor {
     rax: u64,
     rbx: u64,
     rcx: u64,
@@ -15,27 +20,9 @@ struct Processor {
 }
 
 impl Processor {
-    fn _parse_reg(&mut self, reg_type: usize, input: &str) {
-        let value = input.split(':').collect::<Vec<&str>>()[1].trim().parse::<u64>().unwrap();
-        match reg_type {
-            0 => self.rax = value,
-            1 => self.rbx = value,
-            2 => self.rcx = value,
-            _ => panic!("Invalid register")
-        };
-    }
-
-    fn _parse_code(&mut self, input: &str) {
-        let instr = input.split(':').collect::<Vec<&str>>()[1].trim();
-        let instr_list = instr.split(',').collect::<Vec<&str>>();
-        for i in instr_list.chunks(2) {
-            self.code.push((i[0].trim().parse::<u8>().unwrap(), i[1].trim().parse::<u64>().unwrap()));
-        }
-    }
-
     fn _get_combo(&self, operand: u64) -> u64 {
         match operand {
-            0 | 1 | 2 | 3 => operand as u64,
+            0..=3 => operand,
             4 => self.rax,
             5 => self.rbx,
             6 => self.rcx,
@@ -51,7 +38,7 @@ impl Processor {
     }
     
     fn _bxl(&mut self, operand: u64) {
-        self.rbx = self.rbx ^ operand as u64;
+        self.rbx ^= operand;
         self.rip += 1;
     }
 
@@ -69,7 +56,7 @@ impl Processor {
     }
 
     fn _bxc(&mut self) {
-        self.rbx = self.rbx ^ self.rcx;
+        self.rbx ^= self.rcx;
         self.rip += 1;
     }
 
@@ -93,22 +80,11 @@ impl Processor {
         self.rip += 1;
     }
 
-    fn read_from_file(&mut self, filename: &str) {
-        let mut file = File::open(filename).unwrap();
-        let mut file_str = String::new();
-        file.read_to_string(&mut file_str).unwrap();
-        let lines = file_str.lines().collect::<Vec<&str>>();
-        for i in 0..3 {
-            self._parse_reg(i, lines[i]);
-        }
-        self._parse_code(lines[4]);
-    }
-
     fn _print_reg(&self) {
         println!("RAX: {}\nRBX: {}\nRCX: {}", self.rax, self.rbx, self.rcx);
     }
 
-    fn execute(&mut self, print: bool) {
+    fn execute(&mut self) {
         while self.rip < self.code.len() as u64 {
             let instr = self.code[self.rip as usize];
 
@@ -124,15 +100,15 @@ impl Processor {
                 _ => panic!("Invalid opcode")
             }
         }
+    }
 
-        if print && self.output.len() > 0 {
-            println!("{}", self.output.iter().map(|x| x.to_string()).collect::<Vec<String>>().join(","));
-        }
+    fn output_str(&self) -> String {
+        self.output.iter().map(|x| x.to_string()).collect::<Vec<String>>().join(",")
     }
 }
 
-fn calculateRAX(processor: &mut Processor, sequence: Vec<u8>, revcode: &Vec<(u8, u64)>, rax: &u64) -> u64 {
-    if revcode.len() == 0 {
+fn calculate_rax(processor: &mut Processor, sequence: Vec<u8>, revcode: &Vec<(u8, u64)>, rax: &u64) -> u64 {
+    if revcode.is_empty() {
         return *rax;
     }
 
@@ -141,22 +117,22 @@ fn calculateRAX(processor: &mut Processor, sequence: Vec<u8>, revcode: &Vec<(u8,
     for pair in revcode {
         search.insert(0, pair.1 as u8);
         search.insert(0, pair.0);
-        let mut found_RAX = Vec::new();
+        let mut found_rax = Vec::new();
         for j in rax* 0o100..rax * 0o100 + 0o77{
             processor.rax = j;
             processor.rip = 0;
             processor.output.clear();
-            processor.execute(false);
+            processor.execute();
             if processor.output == search {
-                found_RAX.push(j);
+                found_rax.push(j);
             }
         }
 
         let mut mod_revcode = revcode.clone();
         mod_revcode.remove(0);
 
-        for i in &found_RAX {
-            let tmp = calculateRAX(processor, search.clone(), &mod_revcode, i);
+        for i in &found_rax {
+            let tmp = calculate_rax(processor, search.clone(), &mod_revcode, i);
             if tmp != 0 {
                 return tmp;
             }
@@ -166,25 +142,43 @@ fn calculateRAX(processor: &mut Processor, sequence: Vec<u8>, revcode: &Vec<(u8,
     0
 }
 
-fn main() {
-    let mut processor = Processor {
-        rax: 0,
-        rbx: 0,
-        rcx: 0,
-        rip: 0,
-        code: Vec::new(),
-        output: Vec::new()
-    };
+// Read registers A, B and C followed by the program
+fn new_processor(input: &str) -> Result<Processor, ParseError> {
+    let [registers, program] = blocks_n(input)?;
+    let [rax, rbx, rcx] = registers.context(ints_n(registers.text))?;
+    let numbers = program.context(ints::<u8>(program.text))?;
+    if numbers.len() % 2 != 0 {
+        return Err(ParseError::new("program has an opcode without an operand").at(program.first_line));
+    }
+    let code = numbers.chunks(2).map(|i| (i[0], i[1] as u64)).collect();
+
+    Ok(Processor { rax, rbx, rcx, rip: 0, code, output: Vec::new() })
+}
+
+pub struct Day17;
 
-    processor.read_from_file("input");
-    print!("Part 1: ");
-    processor.execute(true);
+impl Solution for Day17 {
+    type Input = Processor;
+    type Params = ();
 
-    let mut revcode: Vec<(u8, u64)> = processor.code.clone();
-    revcode.reverse();
+    fn parse(input: &str, _params: &Self::Params) -> ParseResult<Self::Input> {
+        Ok(new_processor(input)?)
+    }
 
-    let search = Vec::new();
-    let found_RAX = calculateRAX(&mut processor, search, &revcode, &0);
-    println!("Part 2: {}", found_RAX);
+    fn part1(processor: &Self::Input) -> Answer {
+        let mut processor = processor.clone();
+        processor.execute();
+        processor.output_str().into()
+    }
 
-}
\ No newline at end of file
+    fn part2(processor: &Self::Input) -> Answer {
+        let mut processor = processor.clone();
+
+        let mut revcode: Vec<(u8, u64)> = processor.code.clone();
+        revcode.reverse();
+
+        let search = Vec::new();
+        let found_rax = calculate_rax(&mut processor, search, &revcode, &0);
+        found_rax.into()
+    }
+}
@@ -0,0 +1,123 @@
+use aoc_base::pathfinding::astar;
+use aoc_base::parse::lines;
+use aoc_base::parse::ParseError;
+use aoc_base::parse_param;
+use aoc_base::Answer;
+use aoc_base::Grid;
+use aoc_base::Coord;
+use aoc_base::Params;
+use aoc_base::ParseResult;
+use aoc_base::Solution;
+
+// Read falling bytes from text into vector of coordinates
+fn read_input(contents: &str) -> Result<Vec<Coord>, ParseError> {
+    lines(contents).map(|line| line.ints_n().map(|[x, y]| Coord::from_xy(x, y))).collect()
+}
+
+// Simulate n steps of falling bytes, return last position
+fn n_steps(grid: &mut Grid<char>, memory: &mut Vec<Coord>, n: u32) -> Coord {
+    let mut pos = Coord::ZERO;
+    for _ in 0..n {
+        pos = memory.pop().unwrap();
+        grid.set(pos, '#');
+    }
+    pos
+}
+
+// Shortest path from the top left to the bottom right corner, None if the exit is blocked
+fn shortest_path(grid: &Grid<char>) -> Option<usize> {
+    let end = Coord::from_rc(grid.height - 1, grid.width - 1);
+    let successors = |c: &Coord| grid.same_neighbors(c).map(|(n, _, _)| (n, 1)).collect::<Vec<_>>();
+    let heuristic = |c: &Coord| c.manhattan(&end) as usize;
+    astar(Coord::ZERO, successors, heuristic, |&c| c == end).map(|(_, cost)| cost)
+}
+
+// Create empty memory grid and let the first `fallen` bytes fall into it
+fn setup(memory: &Memory, fallen: u32) -> (Grid<char>, Vec<Coord>) {
+    let mut grid = Grid::new_size(memory.size, memory.size);
+    let mut coords = memory.bytes.to_vec();
+
+    // Reverse coords to make popping easier
+    coords.reverse();
+    n_steps(&mut grid, &mut coords, fallen);
+    (grid, coords)
+}
+
+// Size of the memory space and number of bytes fallen before the first part
+pub struct MemoryParams {
+    pub size: u32,
+    pub bytes: u32,
+}
+
+impl Default for MemoryParams {
+    fn default() -> Self {
+        MemoryParams { size: 71, bytes: 1024 }
+    }
+}
+
+impl Params for MemoryParams {
+    fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "size" => self.size = parse_param(key, value)?,
+            "bytes" => self.bytes = parse_param(key, value)?,
+            _ => return Err(format!("unknown parameter {:?}", key)),
+        }
+        if self.size == 0 {
+            return Err("memory space has to be at least 1x1".to_string());
+        }
+        Ok(())
+    }
+}
+
+// Falling bytes in a square memory space
+pub struct Memory {
+    bytes: Vec<Coord>,
+    size: u32,
+    first_bytes: u32,
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Memory;
+    type Params = MemoryParams;
+
+    fn parse(input: &str, params: &Self::Params) -> ParseResult<Self::Input> {
+        let bytes = read_input(input)?;
+        if bytes.len() < params.bytes as usize {
+            return Err(format!("{} bytes should fall first, the input has only {}", params.bytes, bytes.len()).into());
+        }
+        let size = params.size as i32;
+        if let Some(c) = bytes.iter().find(|c| !(0..size).contains(&c.x()) || !(0..size).contains(&c.y())) {
+            return Err(format!("byte {},{} lies outside of the memory space of size {}", c.x(), c.y(), size).into());
+        }
+        Ok(Memory { bytes, size: params.size, first_bytes: params.bytes })
+    }
+
+    fn part1(memory: &Self::Input) -> Answer {
+        let (grid, _) = setup(memory, memory.first_bytes);
+
+        // Get the shortest path, too many fallen bytes may block the exit
+        match shortest_path(&grid) {
+            Some(cost) => cost.into(),
+            None => "not reachable".into(),
+        }
+    }
+
+    fn part2(memory: &Self::Input) -> Answer {
+        // Skip the first bytes unless they already block the exit
+        let (mut grid, mut coords) = setup(memory, memory.first_bytes);
+        if shortest_path(&grid).is_none() {
+            (grid, coords) = setup(memory, 0);
+        }
+
+        // Try to add a step at a time and check if end is still reachable
+        for _ in 0..coords.len() {
+            let pos = n_steps(&mut grid, &mut coords, 1);
+            if shortest_path(&grid).is_none() {
+                return format!("{},{}", pos.x(), pos.y()).into();
+            }
+        }
+        "not found".into()
+    }
+}
@@ -1,13 +1,8 @@
-use std::io::Read;
 use std::collections::HashMap;
-
-// Read input from file
-fn read_input(filename: &str) -> String {
-    let mut file = std::fs::File::open(filename).unwrap();
-    let mut contents = String::new();
-    file.read_to_string(&mut contents).unwrap();
-    contents
-}
+use aoc_base::parse::blocks_n;
+use aoc_base::Answer;
+use aoc_base::ParseResult;
+use aoc_base::Solution;
 
 // Return hashmap of towels contained in a sequence
 fn seq_towels<'a>(towels: &Vec<&'a str>, seq: &str) -> HashMap<&'a str, bool> {
@@ -35,7 +30,7 @@ fn try_combination<'a>(towels: &HashMap<&str, bool>, sequence: &'a str, cache: &
         let subsequence = &sequence[0..i];
         if towels.contains_key(subsequence) {
             let new_subsequence = &sequence[i..];
-            if new_subsequence.len() == 0 {
+            if new_subsequence.is_empty() {
                 // Solved subsequence
                 cache.insert(sequence, found + 1);
                 return found + 1;
@@ -46,21 +41,21 @@ fn try_combination<'a>(towels: &HashMap<&str, bool>, sequence: &'a str, cache: &
     }
 
     // Store results in cache
-    if cache.contains_key(sequence) {
-        cache.insert(sequence, found);
-    } else {
-        cache.insert(sequence, found);
-    }
+    cache.insert(sequence, found);
 
     found
 }
 
-fn main() {
-    let x = read_input("input");
-    let parts = x.split("\n\n").collect::<Vec<&str>>();
-    
-    let towels = parts[0].split(", ").collect::<Vec<&str>>();
-    let sequences = parts[1].split("\n").collect::<Vec<&str>>();
+// Available towel patterns and designs to make from them
+pub struct Onsen {
+    towels: Vec<String>,
+    sequences: Vec<String>,
+}
+
+// Count designs that can be made (first) and all the ways they can be made (second)
+fn count_designs(onsen: &Onsen) -> (u64, u64) {
+    let towels = onsen.towels.iter().map(|t| t.as_str()).collect::<Vec<&str>>();
+    let sequences = onsen.sequences.iter().map(|s| s.as_str()).collect::<Vec<&str>>();
 
     let mut cache = HashMap::new();
 
@@ -72,7 +67,27 @@ fn main() {
         part1 += if *cache.get(s).unwrap() != 0 { 1 } else { 0 }; 
     }
 
-    println!("Part 1: {}", part1);
-    println!("Part 2: {}", part2);
+    (part1, part2)
+}
+
+pub struct Day19;
 
-}
\ No newline at end of file
+impl Solution for Day19 {
+    type Input = Onsen;
+    type Params = ();
+
+    fn parse(input: &str, _params: &Self::Params) -> ParseResult<Self::Input> {
+        let [towels, designs] = blocks_n(input)?;
+        let towels = towels.text.split(',').map(|t| t.trim().to_string()).collect();
+        let sequences = designs.lines().map(|l| l.text.trim().to_string()).collect();
+        Ok(Onsen { towels, sequences })
+    }
+
+    fn part1(onsen: &Self::Input) -> Answer {
+        count_designs(onsen).0.into()
+    }
+
+    fn part2(onsen: &Self::Input) -> Answer {
+        count_designs(onsen).1.into()
+    }
+}
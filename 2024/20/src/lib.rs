@@ -0,0 +1,140 @@
+use std::collections::HashMap;
+use aoc_base::pathfinding::bfs;
+use aoc_base::parse::ParseError;
+use aoc_base::parse_param;
+use aoc_base::Answer;
+use aoc_base::Grid;
+use aoc_base::Coord;
+use aoc_base::Params;
+use aoc_base::ParseResult;
+use aoc_base::Solution;
+
+// Get all neighbors reachable from a point (start) in a certain distance
+// This function is recursive and starts with start == current point
+fn get_neig_in_dist(grid: &Grid<char>, start: &Coord, current: &Coord, neighbors: &mut Vec<Coord>, visited: &mut Vec<Coord>, dist: u32) {
+    if visited.contains(current) {
+        return;
+    }
+
+    visited.push(*current);
+
+    for (new_neigh, _, &cell) in grid.neighbors(current) {
+        if visited.contains(&new_neigh) {
+            continue;
+        }
+        if start.manhattan(&new_neigh) <= dist {
+            if cell == '.' {
+                neighbors.push(new_neigh);
+            }
+            get_neig_in_dist(grid, start, &new_neigh, neighbors, visited, dist);
+        }
+    }
+}
+
+// See how many cheats saving at least `threshold` are possible for various cheat steps
+fn solve(paths: &Vec<Coord>, grid: &Grid<char>, dist: &HashMap<Coord, usize>, original_distance: usize, cheat_steps: u32, threshold: i32) -> u32 {
+    let mut cheats_map = HashMap::new();
+
+    let mut neighbors = Vec::new();
+    let mut visited = Vec::new();
+
+    // For each point in path
+    for p in paths {
+        neighbors.clear();
+        visited.clear();
+        // Look for all possible cheats (neighbors with distance < cheat_steps)
+        get_neig_in_dist(grid, p, p, &mut neighbors, &mut visited, cheat_steps);
+        for n in &neighbors {
+            if dist[p] > dist[n] { // If the neighbor is going back to the start
+                continue;
+            }
+            // Calculate the cutted distance
+            let cutted = original_distance as i32 - (dist[p] as i32 + p.manhattan(n) as i32 + (original_distance - dist[n]) as i32);
+            if cheats_map.contains_key(&cutted) {
+                cheats_map.insert(cutted, cheats_map[&cutted] + 1);
+            } else {
+                cheats_map.insert(cutted, 1);
+            }
+        }
+    }
+
+    let mut sum = 0;
+    for (k, v) in &cheats_map {
+        if *k >= threshold {
+            sum += v;
+        }
+    }
+
+    sum
+}
+
+// Picoseconds a cheat has to save and the length of cheats in each part
+pub struct CheatParams {
+    pub threshold: u32,
+    pub cheat1: u32,
+    pub cheat2: u32,
+}
+
+impl Default for CheatParams {
+    fn default() -> Self {
+        CheatParams { threshold: 100, cheat1: 2, cheat2: 20 }
+    }
+}
+
+impl Params for CheatParams {
+    fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "threshold" => self.threshold = parse_param(key, value)?,
+            "cheat1" => self.cheat1 = parse_param(key, value)?,
+            "cheat2" => self.cheat2 = parse_param(key, value)?,
+            _ => return Err(format!("unknown parameter {:?}", key)),
+        }
+        Ok(())
+    }
+}
+
+// Racetrack with start and end replaced by track
+pub struct Racetrack {
+    grid: Grid<char>,
+    start: Coord,
+    end: Coord,
+    threshold: u32,
+    cheat1: u32,
+    cheat2: u32,
+}
+
+// Find the racetrack path with distances from start and count cheats for given cheat steps
+fn count_cheats(track: &Racetrack, cheat_steps: u32) -> u32 {
+    let Racetrack { grid, start, end, threshold, .. } = track;
+
+    // Get the shortest path
+    let paths = bfs(*start, |c| grid.same_neighbors(c).map(|(n, _, _)| n).collect::<Vec<_>>());
+    let dist = paths.distances();
+    let original_distance = dist[end];
+
+    solve(&grid.find('.'), grid, dist, original_distance, cheat_steps, *threshold as i32)
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Racetrack;
+    type Params = CheatParams;
+
+    fn parse(input: &str, params: &Self::Params) -> ParseResult<Self::Input> {
+        let mut grid = Grid::try_from_str(input)?;
+        let start = grid.find_first('S').ok_or(ParseError::new("racetrack has no start 'S'"))?;
+        let end = grid.find_first('E').ok_or(ParseError::new("racetrack has no end 'E'"))?;
+        grid.set(start, '.');
+        grid.set(end, '.');
+        Ok(Racetrack { grid, start, end, threshold: params.threshold, cheat1: params.cheat1, cheat2: params.cheat2 })
+    }
+
+    fn part1(track: &Self::Input) -> Answer {
+        count_cheats(track, track.cheat1).into()
+    }
+
+    fn part2(track: &Self::Input) -> Answer {
+        count_cheats(track, track.cheat2).into()
+    }
+}
@@ -0,0 +1,142 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+use aoc_base::parse::lines;
+use aoc_base::parse::ParseError;
+use aoc_base::parse_param;
+use aoc_base::Answer;
+use aoc_base::Params;
+use aoc_base::ParseResult;
+use aoc_base::Solution;
+
+// Read numbers from text
+fn read_input(contents: &str) -> Result<Vec<u64>, ParseError> {
+    lines(contents).map(|line| line.value()).collect()
+}
+
+// Calculate next number in sequence
+fn next_number(secret: &mut u64) {
+    *secret ^= (*secret << 6) & 0xFFFFFF;
+    *secret ^= (*secret >> 5) & 0xFFFFFF;
+    *secret ^= (*secret << 11) & 0xFFFFFF;
+}
+
+// Get last digit of a number
+fn digit(n: u64) -> i8 {
+    (n % 10) as i8
+}
+
+// Convert sequence of differences to a number
+fn seq2number(sequence: &[i8]) -> u32 {
+    let mut result: u32 = 0;
+    for diff in sequence.iter().take(4) {
+        result <<= 1;
+        if *diff < 0 {
+            result |= 1;
+        }
+        result <<= 5;
+        result |= diff.unsigned_abs() as u32;
+    }
+    result
+}
+
+// Do n round of next number generation and update global map
+fn do_n_rounds(secret: &mut u64, n: u64, global: &mut HashMap<u32, u32>) {
+    let mut seen_sequences = HashSet::new();
+    let mut prev_sequence = Vec::with_capacity(4);
+    let mut prev_number = *secret;
+
+    // Generate the first 3 numbers
+    for _ in 0..3 {
+        next_number(secret);
+        prev_sequence.push(digit(*secret) - digit(prev_number));
+        prev_number = *secret;
+    }
+    
+    prev_sequence.push(0); // padding 
+
+    // Generate the rest but also update global map
+    for _ in 0..n-3 {
+        // Generate next number, append it to sequence of differences
+        next_number(secret);
+        *prev_sequence.last_mut().unwrap() = digit(*secret) - digit(prev_number);
+        prev_number = *secret;
+
+        // Get number for this sequence
+        let seqnum = seq2number(&prev_sequence);
+        if seen_sequences.contains(&seqnum) {
+            prev_sequence.rotate_left(1);
+            continue;
+        }
+        // Not seen this sequence, update global map
+        seen_sequences.insert(seqnum);
+        let entry = global.entry(seqnum).or_insert(0);
+        *entry += digit(*secret) as u32;
+        // Shift sequence
+        prev_sequence.rotate_left(1);
+    }
+}
+
+// Generate secret numbers for all buyers, return their sum (first) and the best amount of bananas (second)
+fn simulate_buyers(market: &Market) -> (u64, u32) {
+    let mut secrets = market.secrets.to_vec();
+
+    let mut global = HashMap::new();
+    let mut result = 0;
+
+    for s in &mut secrets {
+        do_n_rounds(s, market.rounds, &mut global);
+        result += *s;
+    }
+
+    (result, *global.values().max().unwrap())
+}
+
+// Number of secret numbers each buyer generates
+pub struct MarketParams {
+    pub rounds: u64,
+}
+
+impl Default for MarketParams {
+    fn default() -> Self {
+        MarketParams { rounds: 2000 }
+    }
+}
+
+impl Params for MarketParams {
+    fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "rounds" => self.rounds = parse_param(key, value)?,
+            _ => return Err(format!("unknown parameter {:?}", key)),
+        }
+        // Differences of the first numbers form the initial sequence
+        if self.rounds < 4 {
+            return Err(format!("at least 4 rounds are needed, got {}", self.rounds));
+        }
+        Ok(())
+    }
+}
+
+// Initial secrets of the buyers
+pub struct Market {
+    secrets: Vec<u64>,
+    rounds: u64,
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Market;
+    type Params = MarketParams;
+
+    fn parse(input: &str, params: &Self::Params) -> ParseResult<Self::Input> {
+        Ok(Market { secrets: read_input(input)?, rounds: params.rounds })
+    }
+
+    fn part1(market: &Self::Input) -> Answer {
+        simulate_buyers(market).0.into()
+    }
+
+    fn part2(market: &Self::Input) -> Answer {
+        simulate_buyers(market).1.into()
+    }
+}
@@ -0,0 +1,103 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+
+use aoc_base::Answer;
+use toml::Value;
+
+use crate::store;
+
+// Registry of accepted answers, kept in the data directory next to the inputs
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+// Verified answers of the real inputs, stored as
+//
+// [2024.16]
+// part1 = 114476
+// part2 = 508
+#[derive(Debug, Default, Clone)]
+pub struct Registry {
+    answers: BTreeMap<(u16, u8, u8), Answer>,
+}
+
+fn to_value(answer: &Answer) -> Value {
+    match answer {
+        Answer::Number(n) => Value::Integer(*n),
+        Answer::Text(s) => Value::String(s.clone()),
+    }
+}
+
+fn from_value(value: &Value) -> Option<Answer> {
+    match value {
+        Value::Integer(n) => Some(Answer::Number(*n)),
+        Value::String(s) => Some(Answer::Text(s.clone())),
+        _ => None,
+    }
+}
+
+impl Registry {
+    // Load the registry, a missing file is an empty registry
+    pub fn load(path: &Path) -> Result<Registry, String> {
+        store::load(path)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        store::save(self, path)
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: u8) -> Option<&Answer> {
+        self.answers.get(&(year, day, part))
+    }
+
+    pub fn insert(&mut self, year: u16, day: u8, part: u8, answer: Answer) {
+        self.answers.insert((year, day, part), answer);
+    }
+
+    // All recorded answers ordered by year, day and part
+    pub fn iter(&self) -> impl Iterator<Item = ((u16, u8, u8), &Answer)> {
+        self.answers.iter().map(|(k, v)| (*k, v))
+    }
+
+    pub fn len(&self) -> usize {
+        self.answers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.answers.is_empty()
+    }
+}
+
+impl FromStr for Registry {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Registry, String> {
+        let mut registry = Registry::default();
+        for entry in store::day_tables(s)? {
+            for (part_key, value) in entry.table.iter() {
+                let part = match part_key.as_str() {
+                    "part1" => 1,
+                    "part2" => 2,
+                    _ => return Err(format!("unknown key {:?} in {}", part_key, entry.path)),
+                };
+                let answer = from_value(value).ok_or(format!("{}.{} is not a number or a string", entry.path, part_key))?;
+                registry.insert(entry.year, entry.day, part, answer);
+            }
+        }
+        Ok(registry)
+    }
+}
+
+impl fmt::Display for Registry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut last = None;
+        for ((year, day, part), answer) in self.iter() {
+            if last != Some((year, day)) {
+                store::write_day_header(f, last.is_none(), year, day)?;
+                last = Some((year, day));
+            }
+            writeln!(f, "part{} = {}", part, to_value(answer))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,155 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::Duration;
+
+use toml::Value;
+
+use crate::store;
+
+// Timings of the last saved benchmark run, machine specific so not tracked by git
+pub const BASELINE_FILE: &str = "bench-baseline.toml";
+
+// Measured steps of a day, in the order they are run
+pub const PHASES: [&str; 3] = ["parse", "part1", "part2"];
+
+// Summary of the durations of repeated runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub mean: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    // Summarize measured durations, there has to be at least one
+    pub fn from_samples(samples: &mut [Duration]) -> Stats {
+        assert!(!samples.is_empty(), "no samples to summarize");
+        samples.sort();
+        let n = samples.len();
+        let mean = samples.iter().sum::<Duration>() / n as u32;
+        // Nearest rank percentile
+        let rank = |p: usize| samples[(n * p).div_ceil(100).max(1) - 1];
+        Stats { mean, median: rank(50), p95: rank(95) }
+    }
+}
+
+// Stats of parsing and of both parts, ordered as `PHASES`
+pub type Timings = [Stats; 3];
+
+// Format a duration in the largest unit keeping it above one
+pub fn format_duration(d: Duration) -> String {
+    let nanos = d.as_nanos() as f64;
+    if nanos < 1e3 {
+        format!("{:.0}ns", nanos)
+    } else if nanos < 1e6 {
+        format!("{:.1}µs", nanos / 1e3)
+    } else if nanos < 1e9 {
+        format!("{:.1}ms", nanos / 1e6)
+    } else {
+        format!("{:.2}s", nanos / 1e9)
+    }
+}
+
+// Median durations of earlier runs to compare against, stored in nanoseconds as
+//
+// [2024.16]
+// parse = 41250
+// part1 = 8702541
+// part2 = 9120333
+#[derive(Debug, Default, Clone)]
+pub struct Baseline {
+    medians: BTreeMap<(u16, u8), [Duration; 3]>,
+}
+
+impl Baseline {
+    // Load the baseline, a missing file is an empty baseline
+    pub fn load(path: &Path) -> Result<Baseline, String> {
+        store::load(path)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        store::save(self, path)
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<&[Duration; 3]> {
+        self.medians.get(&(year, day))
+    }
+
+    pub fn insert(&mut self, year: u16, day: u8, timings: &Timings) {
+        self.medians.insert((year, day), timings.map(|s| s.median));
+    }
+
+    pub fn len(&self) -> usize {
+        self.medians.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.medians.is_empty()
+    }
+}
+
+impl FromStr for Baseline {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Baseline, String> {
+        let mut baseline = Baseline::default();
+        for entry in store::day_tables(s)? {
+            let mut medians = [Duration::ZERO; 3];
+            for (median, phase) in medians.iter_mut().zip(PHASES) {
+                *median = match entry.table.get(phase) {
+                    Some(Value::Integer(n)) if *n >= 0 => Duration::from_nanos(*n as u64),
+                    _ => return Err(format!("{}.{} is not a number of nanoseconds", entry.path, phase)),
+                };
+            }
+            baseline.medians.insert((entry.year, entry.day), medians);
+        }
+        Ok(baseline)
+    }
+}
+
+impl fmt::Display for Baseline {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, ((year, day), medians)) in self.medians.iter().enumerate() {
+            store::write_day_header(f, i == 0, *year, *day)?;
+            for (phase, median) in PHASES.iter().zip(medians) {
+                writeln!(f, "{} = {}", phase, median.as_nanos())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn stats() {
+        let mut samples: Vec<_> = (1..=20).rev().map(ms).collect();
+        let stats = Stats::from_samples(&mut samples);
+        assert_eq!(stats, Stats { mean: Duration::from_micros(10500), median: ms(10), p95: ms(19) });
+
+        let stats = Stats::from_samples(&mut [ms(3)]);
+        assert_eq!(stats, Stats { mean: ms(3), median: ms(3), p95: ms(3) });
+    }
+
+    #[test]
+    fn baseline_round_trip() {
+        let stats = |n| Stats { mean: ms(n), median: ms(n), p95: ms(n) };
+        let mut baseline = Baseline::default();
+        baseline.insert(2024, 16, &[stats(1), stats(20), stats(300)]);
+        baseline.insert(2024, 10, &[stats(2), stats(3), stats(4)]);
+
+        let parsed: Baseline = baseline.to_string().parse().unwrap();
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed.get(2024, 16), Some(&[ms(1), ms(20), ms(300)]));
+        assert_eq!(parsed.get(2024, 10), Some(&[ms(2), ms(3), ms(4)]));
+        assert!("[2024.16]\nparse = 1\n".parse::<Baseline>().is_err());
+    }
+}
@@ -0,0 +1,121 @@
+use std::error::Error;
+use std::fmt;
+use std::hint::black_box;
+use std::time::Instant;
+
+use crate::bench::Stats;
+use crate::bench::Timings;
+use aoc_base::Answer;
+use aoc_base::Params;
+use aoc_base::Solution;
+
+// Answers of parts that were run, numbered by part
+pub type Answers = Vec<(u8, Answer)>;
+
+// Parameters of a day as `key` and `value` pairs
+pub type ParamList = [(String, String)];
+
+// Failure of running a day, invalid parameters are told apart from invalid input
+#[derive(Debug)]
+pub enum RunError {
+    Params(String),
+    Input(Box<dyn Error>),
+}
+
+impl fmt::Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RunError::Params(e) => write!(f, "invalid parameters: {}", e),
+            RunError::Input(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for RunError {}
+
+impl From<Box<dyn Error>> for RunError {
+    fn from(e: Box<dyn Error>) -> Self {
+        RunError::Input(e)
+    }
+}
+
+// Solution of a single day, input is parsed once with the given parameters (`key`, `value`)
+// and selected parts (or both) are run on it
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub solve: fn(&str, &ParamList, Option<u8>) -> Result<Answers, RunError>,
+    // Time parsing and both parts over a number of iterations
+    pub bench: fn(&str, &ParamList, u32) -> Result<Timings, RunError>,
+}
+
+fn settings<S: Solution>(params: &ParamList) -> Result<S::Params, RunError> {
+    let mut settings = S::Params::default();
+    for (key, value) in params {
+        settings.set(key, value).map_err(RunError::Params)?;
+    }
+    Ok(settings)
+}
+
+fn solve<S: Solution>(input: &str, params: &ParamList, part: Option<u8>) -> Result<Answers, RunError> {
+    let parsed = S::parse(input, &settings::<S>(params)?)?;
+    let mut answers = Vec::new();
+    if part.is_none() || part == Some(1) {
+        answers.push((1, S::part1(&parsed)));
+    }
+    if part.is_none() || part == Some(2) {
+        answers.push((2, S::part2(&parsed)));
+    }
+    Ok(answers)
+}
+
+fn bench<S: Solution>(input: &str, params: &ParamList, iterations: u32) -> Result<Timings, RunError> {
+    let settings = settings::<S>(params)?;
+    let mut samples = [Vec::new(), Vec::new(), Vec::new()];
+    for _ in 0..iterations.max(1) {
+        let start = Instant::now();
+        let parsed = S::parse(black_box(input), &settings)?;
+        samples[0].push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part1(&parsed));
+        samples[1].push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part2(&parsed));
+        samples[2].push(start.elapsed());
+    }
+    Ok(samples.map(|mut s| Stats::from_samples(&mut s)))
+}
+
+macro_rules! day {
+    ($year:literal, $day:literal, $solution:path) => {
+        Day { year: $year, day: $day, solve: solve::<$solution>, bench: bench::<$solution> }
+    };
+}
+
+// All solved days, ordered by year and day
+pub const DAYS: &[Day] = &[
+    day!(2024, 10, aoc_2024_10::Day10),
+    day!(2024, 11, aoc_2024_11::Day11),
+    day!(2024, 12, aoc_2024_12::Day12),
+    day!(2024, 13, aoc_2024_13::Day13),
+    day!(2024, 14, aoc_2024_14::Day14),
+    day!(2024, 15, aoc_2024_15::Day15),
+    day!(2024, 16, aoc_2024_16::Day16),
+    day!(2024, 17, aoc_2024_17::Day17),
+    day!(2024, 18, aoc_2024_18::Day18),
+    day!(2024, 19, aoc_2024_19::Day19),
+    day!(2024, 20, aoc_2024_20::Day20),
+    day!(2024, 22, aoc_2024_22::Day22),
+];
+
+// Find solution for a given year and day
+pub fn find(year: u16, day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.year == year && d.day == day)
+}
+
+// Get all solutions of a given year
+pub fn of_year(year: u16) -> impl Iterator<Item = &'static Day> {
+    DAYS.iter().filter(move |d| d.year == year)
+}
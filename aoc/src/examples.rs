@@ -0,0 +1,61 @@
+use aoc_base::input::InputLoader;
+use aoc_base::input::Variant;
+use toml::Table;
+use toml::Value;
+
+use crate::store;
+
+// Examples of a day are described in `<year>/<day>/examples.toml`, one table per stored input
+// with optional `part1`, `part2` and `params`
+pub const EXAMPLES_FILE: &str = "examples.toml";
+
+// Stored example input with the settings it needs and its expected answers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub variant: Variant,
+    pub params: Vec<(String, String)>,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Example {
+    pub fn expected(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+// Answers and parameters are written as numbers or strings, use them the way they are printed
+fn text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+// Load all examples of a day, a day without the file has none
+pub fn load(loader: &InputLoader, year: u16, day: u8) -> Result<Vec<Example>, String> {
+    let path = loader.path(year, day, &Variant::Real).with_file_name(EXAMPLES_FILE);
+    let tables: Table = store::load(&path)?;
+
+    let mut examples = Vec::new();
+    for (name, example) in tables.iter() {
+        let variant = name.parse().map_err(|e| format!("{}: {}", path.display(), e))?;
+        let params = example.get("params")
+            .and_then(Value::as_table)
+            .map(|t| t.iter().map(|(k, v)| (k.clone(), text(v))).collect())
+            .unwrap_or_default();
+        let part1 = example.get("part1").map(text);
+        let part2 = example.get("part2").map(text);
+        examples.push(Example { variant, params, part1, part2 });
+    }
+    Ok(examples)
+}
+
+// Example stored as a given variant, if it is described
+pub fn find(loader: &InputLoader, year: u16, day: u8, variant: &Variant) -> Result<Option<Example>, String> {
+    Ok(load(loader, year, day)?.into_iter().find(|e| &e.variant == variant))
+}
@@ -0,0 +1,6 @@
+// Registry of solved days, their examples, verified answers and benchmark helpers, shared by the runner and the tests
+pub mod answers;
+pub mod bench;
+pub mod days;
+pub mod examples;
+pub mod store;
@@ -0,0 +1,316 @@
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Duration;
+
+use aoc::answers::Registry;
+use aoc::answers::ANSWERS_FILE;
+use aoc::bench::format_duration;
+use aoc::bench::Baseline;
+use aoc::bench::BASELINE_FILE;
+use aoc::bench::PHASES;
+use aoc::days;
+use aoc::days::Day;
+use aoc::days::ParamList;
+use aoc::days::RunError;
+use aoc::examples;
+use aoc_base::input::read_path;
+use aoc_base::input::InputLoader;
+use aoc_base::input::Variant;
+use clap::Args;
+use clap::Parser;
+use clap::Subcommand;
+
+#[derive(Parser)]
+#[command(about = "Advent of Code solutions runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run solutions for a given year and day
+    Run {
+        /// Year of the puzzle
+        year: u16,
+        /// Day of the puzzle
+        #[arg(required_unless_present = "all", conflicts_with = "all")]
+        day: Option<u8>,
+        /// Run all solved days of the year
+        #[arg(long)]
+        all: bool,
+        /// Run only one part of the puzzle
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Override a puzzle setting of the day, e.g. `--param width=11`
+        #[arg(long = "param", value_name = "KEY=VALUE", value_parser = parse_key_value, conflicts_with = "all")]
+        params: Vec<(String, String)>,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Re-run solved days on the real inputs and compare them with answers in answers.toml
+    Check {
+        /// Check only days of a given year
+        year: Option<u16>,
+        /// Record answers of parts which have none in answers.toml yet
+        #[arg(long)]
+        accept: bool,
+        /// Directory with inputs and answers.toml [default: $AOC_DATA_DIR or the repository]
+        #[arg(long)]
+        data_dir: Option<PathBuf>,
+    },
+    /// Time parsing and both parts of solved days on the real inputs and compare them with a saved baseline
+    Bench {
+        /// Benchmark only days of a given year
+        year: Option<u16>,
+        /// Benchmark only a single day of the year
+        day: Option<u8>,
+        /// Number of runs of each day
+        #[arg(long, default_value_t = 10)]
+        iterations: u32,
+        /// Slowdown of the median against the baseline (in percent) reported as a regression
+        #[arg(long, default_value_t = 20)]
+        tolerance: u32,
+        /// Save the medians as the new baseline
+        #[arg(long)]
+        save: bool,
+        /// Directory with inputs and bench-baseline.toml [default: $AOC_DATA_DIR or the repository]
+        #[arg(long)]
+        data_dir: Option<PathBuf>,
+    },
+}
+
+#[derive(Args)]
+struct InputArgs {
+    /// Read the input from a file instead, `-` reads standard input
+    #[arg(long, conflicts_with_all = ["all", "variant"])]
+    input: Option<PathBuf>,
+    /// Stored input to use, e.g. example1 [default: real]
+    #[arg(long)]
+    variant: Option<Variant>,
+    /// Directory with inputs stored as <year>/<day>/<variant> [default: $AOC_DATA_DIR or the repository]
+    #[arg(long)]
+    data_dir: Option<PathBuf>,
+}
+
+// Split a `key=value` setting
+fn parse_key_value(arg: &str) -> Result<(String, String), String> {
+    let (key, value) = arg.split_once('=').ok_or(format!("expected KEY=VALUE, got {:?}", arg))?;
+    Ok((key.trim().to_string(), value.trim().to_string()))
+}
+
+// Inputs are stored next to the solutions by default
+fn loader(data_dir: Option<PathBuf>) -> InputLoader {
+    match data_dir {
+        Some(dir) => InputLoader::new(dir),
+        None => InputLoader::from_env(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..")),
+    }
+}
+
+// Where the input of a day comes from
+enum Source {
+    Path(PathBuf),
+    Stored(InputLoader, Variant),
+}
+
+impl Source {
+    fn from_args(args: InputArgs) -> Source {
+        if let Some(path) = args.input {
+            return Source::Path(path);
+        }
+        Source::Stored(loader(args.data_dir), args.variant.unwrap_or_default())
+    }
+
+    // Settings of a stored example as described in its examples.toml
+    fn params(&self, day: &Day) -> Result<Vec<(String, String)>, String> {
+        match self {
+            Source::Stored(loader, variant @ Variant::Named(_)) => {
+                Ok(examples::find(loader, day.year, day.day, variant)?.map(|e| e.params).unwrap_or_default())
+            }
+            _ => Ok(Vec::new()),
+        }
+    }
+
+    // Read the input of a day together with a description of where it came from
+    fn read(&self, day: &Day) -> Result<(String, String), String> {
+        let (name, input) = match self {
+            Source::Path(path) => (path.display().to_string(), read_path(path)),
+            Source::Stored(loader, variant) => {
+                let path = loader.path(day.year, day.day, variant);
+                (path.display().to_string(), loader.load(day.year, day.day, variant))
+            }
+        };
+        input.map(|input| (name, input)).map_err(|e| e.to_string())
+    }
+}
+
+// Run selected parts of a single day and print the answers
+fn run_day(day: &Day, part: Option<u8>, params: &ParamList, source: &Source) -> Result<(), String> {
+    let (name, input) = source.read(day)?;
+    // Settings given on the command line override those of an example
+    let mut settings = source.params(day)?;
+    settings.extend_from_slice(params);
+
+    let answers = (day.solve)(&input, &settings, part).map_err(|e| match e {
+        RunError::Params(e) => format!("Invalid parameters of {} day {}: {}", day.year, day.day, e),
+        RunError::Input(e) => format!("Failed to parse {}: {}", name, e),
+    })?;
+    for (part, answer) in answers {
+        println!("Part {}: {}", part, answer);
+    }
+    Ok(())
+}
+
+fn run(year: u16, day: Option<u8>, part: Option<u8>, params: &ParamList, source: &Source) -> Result<(), String> {
+    match day {
+        Some(d) => {
+            let day = days::find(year, d).ok_or(format!("Day {} of {} is not solved", d, year))?;
+            run_day(day, part, params, source)
+        }
+        None => {
+            let mut found = false;
+            for day in days::of_year(year) {
+                found = true;
+                println!("Day {}", day.day);
+                run_day(day, part, params, source)?;
+            }
+            if found { Ok(()) } else { Err(format!("No days of {} are solved", year)) }
+        }
+    }
+}
+
+// Compare answers of all days (of a year) with the registry, report mismatched, missing and new ones
+fn check(year: Option<u16>, accept: bool, loader: &InputLoader) -> Result<(), String> {
+    let path = loader.data_dir().join(ANSWERS_FILE);
+    let mut registry = Registry::load(&path)?;
+    let mut produced = HashSet::new();
+    let mut new = Vec::new();
+    let (mut matching, mut mismatched, mut missing) = (0, 0, 0);
+
+    for day in days::DAYS.iter().filter(|d| year.is_none_or(|y| d.year == y)) {
+        let answers = loader.load(day.year, day.day, &Variant::Real)
+            .map_err(|e| e.to_string())
+            .and_then(|input| (day.solve)(&input, &[], None).map_err(|e| format!("failed to parse input: {}", e)));
+        let answers = match answers {
+            Ok(answers) => answers,
+            Err(e) => {
+                // Recorded answers of the day are reported as missing below
+                println!("{} day {}: {}", day.year, day.day, e);
+                continue;
+            }
+        };
+
+        for (part, answer) in answers {
+            produced.insert((day.year, day.day, part));
+            match registry.get(day.year, day.day, part) {
+                Some(expected) if *expected == answer => matching += 1,
+                Some(expected) => {
+                    println!("{} day {} part {}: expected {}, got {}", day.year, day.day, part, expected, answer);
+                    mismatched += 1;
+                }
+                None => {
+                    println!("{} day {} part {}: new answer {}", day.year, day.day, part, answer);
+                    new.push((day.year, day.day, part, answer));
+                }
+            }
+        }
+    }
+
+    for ((y, d, part), expected) in registry.iter() {
+        if year.is_none_or(|year| y == year) && !produced.contains(&(y, d, part)) {
+            println!("{} day {} part {}: missing, expected {}", y, d, part, expected);
+            missing += 1;
+        }
+    }
+
+    println!("{} matching, {} mismatched, {} missing, {} new", matching, mismatched, missing, new.len());
+
+    if accept && !new.is_empty() {
+        let count = new.len();
+        for (year, day, part, answer) in new {
+            registry.insert(year, day, part, answer);
+        }
+        registry.save(&path)?;
+        println!("Recorded {} new answers in {}", count, path.display());
+    }
+
+    if mismatched + missing > 0 {
+        return Err(format!("{} mismatched and {} missing answers", mismatched, missing));
+    }
+    Ok(())
+}
+
+// Slowdowns smaller than this are measurement noise, not regressions
+const MIN_REGRESSION: Duration = Duration::from_micros(100);
+
+// Options of the bench command
+struct BenchArgs {
+    iterations: u32,
+    tolerance: u32,
+    save: bool,
+}
+
+// Time selected days, print a summary table and report phases slower than the baseline
+fn bench(year: Option<u16>, day: Option<u8>, args: BenchArgs, loader: &InputLoader) -> Result<(), String> {
+    let path = loader.data_dir().join(BASELINE_FILE);
+    let mut baseline = Baseline::load(&path)?;
+    let selected: Vec<&Day> = days::DAYS.iter()
+        .filter(|d| year.is_none_or(|y| d.year == y) && day.is_none_or(|n| d.day == n))
+        .collect();
+    if selected.is_empty() {
+        return Err("No solved days to benchmark".to_string());
+    }
+
+    let mut regressions = 0;
+    println!("{:<8} {:<6} {:>10} {:>10} {:>10} {:>10}", "Day", "Phase", "Mean", "Median", "P95", "Baseline");
+    for day in selected {
+        let input = loader.load(day.year, day.day, &Variant::Real).map_err(|e| e.to_string())?;
+        let timings = (day.bench)(&input, &[], args.iterations)
+            .map_err(|e| format!("Failed to parse input of {} day {}: {}", day.year, day.day, e))?;
+        let previous = baseline.get(day.year, day.day).copied();
+
+        for (i, (phase, stats)) in PHASES.iter().zip(timings.iter()).enumerate() {
+            let label = if i == 0 { format!("{} {}", day.year, day.day) } else { String::new() };
+            let mut line = format!("{:<8} {:<6} {:>10} {:>10} {:>10}", label, phase,
+                format_duration(stats.mean), format_duration(stats.median), format_duration(stats.p95));
+            if let Some(previous) = previous.map(|p| p[i]) {
+                let change = (stats.median.as_secs_f64() / previous.as_secs_f64().max(1e-9) - 1.0) * 100.0;
+                line += &format!(" {:>10} {:>+6.0}%", format_duration(previous), change);
+                if change > args.tolerance as f64 && stats.median > previous + MIN_REGRESSION {
+                    line += "  regression";
+                    regressions += 1;
+                }
+            }
+            println!("{}", line.trim_end());
+        }
+        baseline.insert(day.year, day.day, &timings);
+    }
+
+    if args.save {
+        baseline.save(&path)?;
+        println!("Saved the baseline to {}", path.display());
+    } else if regressions > 0 {
+        return Err(format!("{} phases are more than {}% slower than the baseline", regressions, args.tolerance));
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let result = match cli.command {
+        Command::Run { year, day, all: _, part, params, input } => run(year, day, part, &params, &Source::from_args(input)),
+        Command::Check { year, accept, data_dir } => check(year, accept, &loader(data_dir)),
+        Command::Bench { year, day, iterations, tolerance, save, data_dir } => {
+            bench(year, day, BenchArgs { iterations, tolerance, save }, &loader(data_dir))
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
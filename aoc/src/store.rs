@@ -0,0 +1,61 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+use toml::Table;
+
+// Read a value from a file, a missing file gives the empty value
+pub fn load<T>(path: &Path) -> Result<T, String>
+where
+    T: Default + FromStr,
+    T::Err: fmt::Display,
+{
+    match fs::read_to_string(path) {
+        Ok(text) => text.parse().map_err(|e| format!("{}: {}", path.display(), e)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(T::default()),
+        Err(e) => Err(format!("{}: {}", path.display(), e)),
+    }
+}
+
+pub fn save<T: fmt::Display>(value: &T, path: &Path) -> Result<(), String> {
+    fs::write(path, value.to_string()).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+// Table of a single day in a file with `[year.day]` tables, `path` names it in errors
+pub struct DayTable {
+    pub year: u16,
+    pub day: u8,
+    pub path: String,
+    pub table: Table,
+}
+
+// Read all `[year.day]` tables of a text
+pub fn day_tables(text: &str) -> Result<Vec<DayTable>, String> {
+    let root: Table = text.parse().map_err(|e: toml::de::Error| e.to_string())?;
+    let mut result = Vec::new();
+    for (year_key, days) in root {
+        let year = year_key.parse().map_err(|_| format!("invalid year {:?}", year_key))?;
+        let toml::Value::Table(days) = days else {
+            return Err(format!("{} is not a table", year_key));
+        };
+        for (day_key, table) in days {
+            let path = format!("{}.{}", year_key, day_key);
+            let day = day_key.parse().map_err(|_| format!("invalid day {:?}", path))?;
+            let toml::Value::Table(table) = table else {
+                return Err(format!("{} is not a table", path));
+            };
+            result.push(DayTable { year, day, path, table });
+        }
+    }
+    Ok(result)
+}
+
+// Write the header of a `[year.day]` table, tables after the first are separated by a blank line
+pub fn write_day_header(f: &mut fmt::Formatter, first: bool, year: u16, day: u8) -> fmt::Result {
+    if !first {
+        writeln!(f)?;
+    }
+    writeln!(f, "[{}.{}]", year, day)
+}
@@ -0,0 +1,44 @@
+use aoc::days::DAYS;
+use aoc::examples;
+use aoc_base::input::InputLoader;
+
+#[test]
+fn examples() {
+    let loader = InputLoader::new(concat!(env!("CARGO_MANIFEST_DIR"), "/.."));
+    let mut failures = Vec::new();
+    let mut checked = 0;
+
+    for day in DAYS {
+        for example in examples::load(&loader, day.year, day.day).unwrap() {
+            let label = format!("{} day {} {}", day.year, day.day, example.variant);
+            let input = loader.load(day.year, day.day, &example.variant).unwrap_or_else(|e| panic!("{}: {}", label, e));
+
+            // Run only the parts with an expected answer
+            let part = match (&example.part1, &example.part2) {
+                (Some(_), None) => Some(1),
+                (None, Some(_)) => Some(2),
+                _ => None,
+            };
+
+            let answers = match (day.solve)(&input, &example.params, part) {
+                Ok(answers) => answers,
+                Err(e) => {
+                    failures.push(format!("{}: {}", label, e));
+                    continue;
+                }
+            };
+            for (number, answer) in answers {
+                if let Some(want) = example.expected(number) {
+                    checked += 1;
+                    let got = answer.to_string();
+                    if got != want {
+                        failures.push(format!("{} part {}: expected {}, got {}", label, number, want, got));
+                    }
+                }
+            }
+        }
+    }
+
+    assert!(checked > 0, "no examples found");
+    assert!(failures.is_empty(), "failed examples:\n{}", failures.join("\n"));
+}
@@ -0,0 +1,134 @@
+use std::ops::Add;
+use std::ops::AddAssign;
+use std::ops::Mul;
+use std::ops::Neg;
+use std::ops::Sub;
+use std::ops::SubAssign;
+
+// Position on a grid or an offset between positions, rows grow downwards
+// Puzzles giving positions as x, y should use `from_xy` so that x is the column
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Coord {
+    pub row: i32,
+    pub col: i32,
+}
+
+impl Coord {
+    pub const ZERO: Coord = Coord { row: 0, col: 0 };
+
+    pub const fn from_rc(row: i32, col: i32) -> Coord {
+        Coord { row, col }
+    }
+
+    pub const fn from_xy(x: i32, y: i32) -> Coord {
+        Coord { row: y, col: x }
+    }
+
+    pub fn x(&self) -> i32 {
+        self.col
+    }
+
+    pub fn y(&self) -> i32 {
+        self.row
+    }
+
+    // Number of orthogonal steps to another coordinate
+    pub fn manhattan(&self, other: &Coord) -> u32 {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+
+    // Number of steps to another coordinate when diagonal steps are allowed
+    pub fn chebyshev(&self, other: &Coord) -> u32 {
+        self.row.abs_diff(other.row).max(self.col.abs_diff(other.col))
+    }
+}
+
+impl Add for Coord {
+    type Output = Coord;
+
+    fn add(self, other: Coord) -> Coord {
+        Coord::from_rc(self.row + other.row, self.col + other.col)
+    }
+}
+
+impl Sub for Coord {
+    type Output = Coord;
+
+    fn sub(self, other: Coord) -> Coord {
+        Coord::from_rc(self.row - other.row, self.col - other.col)
+    }
+}
+
+impl Mul<i32> for Coord {
+    type Output = Coord;
+
+    fn mul(self, k: i32) -> Coord {
+        Coord::from_rc(self.row * k, self.col * k)
+    }
+}
+
+impl Neg for Coord {
+    type Output = Coord;
+
+    fn neg(self) -> Coord {
+        Coord::from_rc(-self.row, -self.col)
+    }
+}
+
+impl AddAssign for Coord {
+    fn add_assign(&mut self, other: Coord) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Coord {
+    fn sub_assign(&mut self, other: Coord) {
+        *self = *self - other;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constructors() {
+        let c = Coord::from_xy(3, -2);
+        assert_eq!(c, Coord::from_rc(-2, 3));
+        assert_eq!((c.x(), c.y()), (3, -2));
+        assert_eq!((c.row, c.col), (-2, 3));
+        assert_eq!(Coord::default(), Coord::ZERO);
+        assert!(Coord::from_rc(0, 5) < Coord::from_rc(1, 0));
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = Coord::from_rc(2, -3);
+        let b = Coord::from_rc(-5, 7);
+        assert_eq!(a + b, Coord::from_rc(-3, 4));
+        assert_eq!(a - b, Coord::from_rc(7, -10));
+        assert_eq!(a * 3, Coord::from_rc(6, -9));
+        assert_eq!(a * -1, -a);
+        assert_eq!(-b, Coord::from_rc(5, -7));
+        assert_eq!(a + b - b, a);
+
+        let mut c = a;
+        c += b;
+        assert_eq!(c, a + b);
+        c -= a;
+        assert_eq!(c, b);
+    }
+
+    #[test]
+    fn distances() {
+        let a = Coord::from_rc(1, -2);
+        let b = Coord::from_rc(-3, 4);
+        assert_eq!(a.manhattan(&b), 10);
+        assert_eq!(a.chebyshev(&b), 6);
+        assert_eq!(b.manhattan(&a), 10);
+        assert_eq!(b.chebyshev(&a), 6);
+        assert_eq!(a.manhattan(&a), 0);
+        assert_eq!(Coord::ZERO.chebyshev(&Coord::from_rc(-2, -2)), 2);
+        assert_eq!(Coord::from_rc(i32::MIN, 0).manhattan(&Coord::from_rc(i32::MAX, 0)), u32::MAX);
+    }
+}
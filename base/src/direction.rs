@@ -0,0 +1,301 @@
+use std::error::Error;
+use std::fmt;
+use std::ops::BitOr;
+
+use crate::Coord;
+
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash)]
+pub enum Direction {Up, Down, Left, Right}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [Direction::Up, Direction::Down, Direction::Left, Direction::Right];
+
+    pub fn to_coord(&self) -> Coord {
+        match self {
+            Direction::Up => Coord::from_rc(-1, 0),
+            Direction::Down => Coord::from_rc(1, 0),
+            Direction::Left => Coord::from_rc(0, -1),
+            Direction::Right => Coord::from_rc(0, 1),
+        }
+    }
+
+    pub fn opposite(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+
+    pub fn is_opposite(&self, other: &Direction) -> bool {
+        self.opposite() == *other
+    }
+
+    // Rotate by 90 degrees counter-clockwise
+    pub fn turn_left(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Left => Direction::Down,
+            Direction::Down => Direction::Right,
+            Direction::Right => Direction::Up,
+        }
+    }
+
+    // Rotate by 90 degrees clockwise
+    pub fn turn_right(&self) -> Direction {
+        self.turn_left().opposite()
+    }
+
+    pub fn from_coords(c1: Coord, c2: Coord) -> Direction {
+        let diff = c2 - c1;
+        match (diff.row, diff.col) {
+            (-1, 0) => Direction::Up,
+            (1, 0) => Direction::Down,
+            (0, -1) => Direction::Left,
+            (0, 1) => Direction::Right,
+            _ => panic!("Invalid coordinates"),
+        }
+    }
+}
+
+pub fn all_directions() -> Vec<Direction> {
+    Direction::ALL.to_vec()
+}
+
+// Character which does not name a direction
+#[derive(Debug, PartialEq, Clone, Copy, Eq)]
+pub struct DirectionParseError(pub char);
+
+impl fmt::Display for DirectionParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid direction {:?}", self.0)
+    }
+}
+
+impl Error for DirectionParseError {}
+
+// Read arrows (^v<>), initials (UDLR) or compass points (NESW)
+impl TryFrom<char> for Direction {
+    type Error = DirectionParseError;
+
+    fn try_from(c: char) -> Result<Direction, DirectionParseError> {
+        match c {
+            '^' | 'U' | 'N' => Ok(Direction::Up),
+            'v' | 'D' | 'S' => Ok(Direction::Down),
+            '<' | 'L' | 'W' => Ok(Direction::Left),
+            '>' | 'R' | 'E' => Ok(Direction::Right),
+            _ => Err(DirectionParseError(c)),
+        }
+    }
+}
+
+// Set of directions stored as bits, e.g. the sides of a cell with a fence
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash, Default)]
+pub struct DirSet(u8);
+
+impl DirSet {
+    pub const EMPTY: DirSet = DirSet(0);
+    pub const ALL: DirSet = DirSet(0b1111);
+
+    fn bit(d: Direction) -> u8 {
+        1 << d as u8
+    }
+
+    pub fn contains(&self, d: Direction) -> bool {
+        self.0 & DirSet::bit(d) != 0
+    }
+
+    pub fn insert(&mut self, d: Direction) {
+        self.0 |= DirSet::bit(d);
+    }
+
+    pub fn remove(&mut self, d: Direction) {
+        self.0 &= !DirSet::bit(d);
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    // Directions in the set, in the order of `Direction::ALL`
+    pub fn iter(&self) -> impl Iterator<Item = Direction> {
+        let set = *self;
+        Direction::ALL.into_iter().filter(move |&d| set.contains(d))
+    }
+}
+
+impl From<Direction> for DirSet {
+    fn from(d: Direction) -> DirSet {
+        DirSet(DirSet::bit(d))
+    }
+}
+
+impl FromIterator<Direction> for DirSet {
+    fn from_iter<I: IntoIterator<Item = Direction>>(iter: I) -> DirSet {
+        let mut set = DirSet::EMPTY;
+        for d in iter {
+            set.insert(d);
+        }
+        set
+    }
+}
+
+impl BitOr for DirSet {
+    type Output = DirSet;
+
+    fn bitor(self, other: DirSet) -> DirSet {
+        DirSet(self.0 | other.0)
+    }
+}
+
+// Direction including diagonals, variants are ordered clockwise starting from Up
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash)]
+pub enum Direction8 {Up, UpRight, Right, DownRight, Down, DownLeft, Left, UpLeft}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up, Direction8::UpRight, Direction8::Right, Direction8::DownRight,
+        Direction8::Down, Direction8::DownLeft, Direction8::Left, Direction8::UpLeft,
+    ];
+
+    pub fn to_coord(&self) -> Coord {
+        match self {
+            Direction8::Up => Coord::from_rc(-1, 0),
+            Direction8::UpRight => Coord::from_rc(-1, 1),
+            Direction8::Right => Coord::from_rc(0, 1),
+            Direction8::DownRight => Coord::from_rc(1, 1),
+            Direction8::Down => Coord::from_rc(1, 0),
+            Direction8::DownLeft => Coord::from_rc(1, -1),
+            Direction8::Left => Coord::from_rc(0, -1),
+            Direction8::UpLeft => Coord::from_rc(-1, -1),
+        }
+    }
+
+    // Rotate by `steps` of 45 degrees, positive steps rotate clockwise
+    pub fn rotate(&self, steps: i32) -> Direction8 {
+        Direction8::ALL[(*self as i32 + steps).rem_euclid(8) as usize]
+    }
+
+    pub fn rotate_45(&self) -> Direction8 {
+        self.rotate(1)
+    }
+
+    pub fn rotate_90(&self) -> Direction8 {
+        self.rotate(2)
+    }
+
+    pub fn opposite(&self) -> Direction8 {
+        self.rotate(4)
+    }
+
+    pub fn is_opposite(&self, other: &Direction8) -> bool {
+        self.opposite() == *other
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        (*self as u8) % 2 == 1
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(d: Direction) -> Direction8 {
+        match d {
+            Direction::Up => Direction8::Up,
+            Direction::Down => Direction8::Down,
+            Direction::Left => Direction8::Left,
+            Direction::Right => Direction8::Right,
+        }
+    }
+}
+
+// Which cells count as neighbors: only orthogonal (VonNeumann) or also diagonal (Moore)
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash)]
+pub enum Neighborhood {VonNeumann, Moore}
+
+impl Neighborhood {
+    // Directions to neighboring cells, orthogonal ones first
+    pub fn directions(&self) -> &'static [Direction8] {
+        const MOORE: [Direction8; 8] = [
+            Direction8::Left, Direction8::Up, Direction8::Down, Direction8::Right,
+            Direction8::UpLeft, Direction8::UpRight, Direction8::DownLeft, Direction8::DownRight,
+        ];
+        match self {
+            Neighborhood::VonNeumann => &MOORE[..4],
+            Neighborhood::Moore => &MOORE,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_directions() {
+        for alphabet in ["^v<>", "UDLR", "NSWE"] {
+            let parsed: Vec<Direction> = alphabet.chars().map(|c| c.try_into().unwrap()).collect();
+            assert_eq!(parsed, [Direction::Up, Direction::Down, Direction::Left, Direction::Right], "{}", alphabet);
+        }
+        assert_eq!(Direction::try_from('x'), Err(DirectionParseError('x')));
+        assert_eq!(Direction::try_from('u'), Err(DirectionParseError('u')));
+    }
+
+    #[test]
+    fn turns() {
+        for d in Direction::ALL {
+            assert_eq!(d.turn_left().turn_right(), d);
+            assert_eq!(d.turn_right().turn_left(), d);
+            assert_eq!(d.turn_left().turn_left(), d.opposite());
+            assert_eq!(d.turn_right().turn_right().turn_right().turn_right(), d);
+            assert_eq!(d.opposite().opposite(), d);
+            assert!(d.is_opposite(&d.opposite()));
+            assert_eq!(Direction::from_coords(Coord::ZERO, d.to_coord()), d);
+        }
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+    }
+
+    #[test]
+    fn dir_set() {
+        let mut set = DirSet::EMPTY;
+        assert!(set.is_empty());
+        set.insert(Direction::Right);
+        set.insert(Direction::Up);
+        set.insert(Direction::Up);
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(Direction::Up) && !set.contains(Direction::Down));
+        assert_eq!(set.iter().collect::<Vec<_>>(), [Direction::Up, Direction::Right]);
+
+        set.remove(Direction::Up);
+        set.remove(Direction::Left);
+        assert_eq!(set, DirSet::from(Direction::Right));
+        assert_eq!(set | Direction::Left.into(), [Direction::Left, Direction::Right].into_iter().collect());
+        assert_eq!(Direction::ALL.into_iter().collect::<DirSet>(), DirSet::ALL);
+        assert_eq!(DirSet::ALL.len(), 4);
+    }
+
+    #[test]
+    fn rotate_eight_ways() {
+        assert_eq!(Direction8::Up.rotate(-1), Direction8::UpLeft);
+        assert_eq!(Direction8::Up.rotate(-9), Direction8::UpLeft);
+        assert_eq!(Direction8::Right.rotate(-2), Direction8::Up);
+        assert_eq!(Direction8::Left.rotate(10), Direction8::Up);
+        assert_eq!(Direction8::UpLeft.rotate_45(), Direction8::Up);
+        for d in Direction8::ALL {
+            assert_eq!(d.rotate(3).rotate(-3), d);
+            assert_eq!(d.rotate(-16), d);
+            assert_eq!(d.rotate(-4), d.opposite());
+            assert_eq!(d.to_coord() + d.opposite().to_coord(), Coord::ZERO);
+            assert_ne!(d.is_diagonal(), d.rotate_45().is_diagonal());
+            assert_eq!(d.is_diagonal(), d.to_coord().row != 0 && d.to_coord().col != 0);
+        }
+        assert!(Direction8::DownLeft.is_diagonal());
+        assert!(!Direction8::Down.is_diagonal());
+        assert_eq!(Direction8::ALL.iter().filter(|d| d.is_diagonal()).count(), 4);
+    }
+}
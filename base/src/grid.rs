@@ -0,0 +1,663 @@
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io;
+use std::io::Read;
+use std::ops::Index;
+use std::ops::IndexMut;
+use std::str::FromStr;
+
+mod components;
+mod search;
+mod transform;
+
+pub use components::Labels;
+pub use components::Region;
+pub use transform::Rect;
+
+use crate::Coord;
+use crate::Direction;
+use crate::Direction8;
+use crate::Neighborhood;
+
+// Error of reading a grid, lines and columns are numbered from 1
+#[derive(Debug)]
+pub enum GridParseError {
+    Io(io::Error),
+    Empty,
+    InvalidCell { line: usize, column: usize, value: char, source: Option<Box<dyn Error + Send + Sync>> },
+    RaggedRow { line: usize, width: usize, expected: usize },
+}
+
+impl fmt::Display for GridParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GridParseError::Io(e) => write!(f, "failed to read grid: {}", e),
+            GridParseError::Empty => write!(f, "grid is empty"),
+            GridParseError::InvalidCell { line, column, value, source } => {
+                write!(f, "invalid cell {:?} at line {}, column {}", value, line, column)?;
+                match source {
+                    Some(e) => write!(f, ": {}", e),
+                    None => Ok(()),
+                }
+            }
+            GridParseError::RaggedRow { line, width, expected } => {
+                write!(f, "row at line {} has width {}, expected {}", line, width, expected)
+            }
+        }
+    }
+}
+
+impl Error for GridParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            GridParseError::Io(e) => Some(e),
+            GridParseError::InvalidCell { source: Some(e), .. } => Some(e.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for GridParseError {
+    fn from(e: io::Error) -> Self {
+        GridParseError::Io(e)
+    }
+}
+
+// Cell type which can be read from a single character of a grid
+pub trait GridCell: Sized {
+    fn from_char(c: char) -> Option<Self>;
+}
+
+impl GridCell for char {
+    fn from_char(c: char) -> Option<Self> {
+        Some(c)
+    }
+}
+
+impl GridCell for i8 {
+    fn from_char(c: char) -> Option<Self> {
+        c.to_digit(10).map(|d| d as i8)
+    }
+}
+
+// Common interface of dense and sparse grids
+pub trait GridLike<T>
+where T: PartialEq + Copy {
+    // Get the value at a coordinate, None if there is no cell
+    fn at(&self, c: &Coord) -> Option<T>;
+
+    // Set the value at a coordinate
+    fn set(&mut self, c: Coord, val: T);
+
+    // Find all positions of a given value, ordered row by row
+    fn find(&self, needle: T) -> Vec<Coord>;
+
+    // Smallest rectangle containing all cells, None for an empty grid
+    fn bounds(&self) -> Option<Rect>;
+
+    // Get neighboring cells with their direction and value (only up, down, left, right)
+    fn neighbors(&self, c: &Coord) -> impl Iterator<Item = (Coord, Direction, T)> {
+        let c = *c;
+        [Direction::Left, Direction::Up, Direction::Down, Direction::Right].into_iter().filter_map(move |d| {
+            let neigh = c + d.to_coord();
+            self.at(&neigh).map(|v| (neigh, d, v))
+        })
+    }
+}
+
+// Behaviour of coordinates outside of the grid
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash, Default)]
+pub enum Topology {
+    // There is nothing outside of the grid
+    #[default]
+    Bounded,
+    // Leaving the grid on one side enters it on the opposite side
+    Torus,
+}
+
+// Cells are stored row by row in a single vector
+#[derive(Debug, Clone)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    pub width: i32,
+    pub height: i32,
+    topology: Topology,
+}
+
+// Display each row on a separate line, width (e.g. `{:3}`) pads every cell
+impl<T> fmt::Display for Grid<T>
+where T: fmt::Display {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row.iter() {
+                match f.width() {
+                    Some(w) => write!(f, "{:1$}", cell, w)?,
+                    None => write!(f, "{}", cell)?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, c: Coord) -> &T {
+        &self.cells[self.offset(&c)]
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, c: Coord) -> &mut T {
+        let offset = self.offset(&c);
+        &mut self.cells[offset]
+    }
+}
+
+impl<T> Grid<T> {
+    // Position of a coordinate in the cell vector, panics if out of a bounded grid
+    fn offset(&self, c: &Coord) -> usize {
+        match self.resolve(c) {
+            Some(c) => (c.row * self.width + c.col) as usize,
+            None => panic!("Coordinate {:?} is out of the grid", c),
+        }
+    }
+
+    // Coordinate within the grid according to its topology
+    fn resolve(&self, c: &Coord) -> Option<Coord> {
+        match self.topology {
+            Topology::Bounded => self.valid_coords(c).then_some(*c),
+            Topology::Torus => self.reindex(*c),
+        }
+    }
+
+    pub fn topology(&self) -> Topology {
+        self.topology
+    }
+
+    pub fn set_topology(&mut self, topology: Topology) {
+        self.topology = topology;
+    }
+
+    // Same grid with a different topology
+    pub fn with_topology(mut self, topology: Topology) -> Grid<T> {
+        self.topology = topology;
+        self
+    }
+
+    // Wrap any coordinate around the grid as if it repeated in every direction, None for an empty grid
+    pub fn reindex(&self, c: Coord) -> Option<Coord> {
+        self.wrap(c.row as i64, c.col as i64)
+    }
+
+    fn wrap(&self, row: i64, col: i64) -> Option<Coord> {
+        if self.width == 0 || self.height == 0 {
+            return None;
+        }
+        let row = row.rem_euclid(self.height as i64) as i32;
+        let col = col.rem_euclid(self.width as i64) as i32;
+        Some(Coord::from_rc(row, col))
+    }
+
+    // Move from a coordinate `times` times by `delta`, None when leaving a bounded grid
+    // The target is computed in i64 so that any number of steps wraps around a torus correctly
+    pub fn move_by(&self, c: &Coord, delta: Coord, times: i32) -> Option<Coord> {
+        let row = c.row as i64 + delta.row as i64 * times as i64;
+        let col = c.col as i64 + delta.col as i64 * times as i64;
+        match self.topology {
+            Topology::Bounded => {
+                let target = Coord::from_rc(row.try_into().ok()?, col.try_into().ok()?);
+                self.valid_coords(&target).then_some(target)
+            }
+            Topology::Torus => self.wrap(row, col),
+        }
+    }
+
+    // Check if a coordinate is valid
+    pub fn valid_coords(&self, c: &Coord) -> bool {
+        c.row >= 0 && c.col >= 0 && c.row < self.height && c.col < self.width
+    }
+
+    // All cells of the grid, row by row
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    // All cells of the grid as mutable, row by row
+    pub fn cells_mut(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+
+    // Get a single row of the grid
+    pub fn row(&self, i: usize) -> &[T] {
+        let w = self.width as usize;
+        &self.cells[i * w..(i + 1) * w]
+    }
+
+    // Get a single row of the grid as mutable
+    pub fn row_mut(&mut self, i: usize) -> &mut [T] {
+        let w = self.width as usize;
+        &mut self.cells[i * w..(i + 1) * w]
+    }
+
+    // Iterate over cells of a single column from top to bottom
+    pub fn col(&self, j: usize) -> impl Iterator<Item = &T> {
+        assert!(j < self.width as usize, "Column {} is out of the grid", j);
+        self.cells.iter().skip(j).step_by(self.width as usize)
+    }
+
+    // Get a reference to the value at a coordinate
+    pub fn get(&self, c: &Coord) -> Option<&T> {
+        self.resolve(c).map(|c| &self.cells[(c.row * self.width + c.col) as usize])
+    }
+
+    // Neighbor of a coordinate by `delta`, None when it is outside of a bounded grid, or when a torus
+    // only one or two cells wide wraps it onto the cell itself or onto a neighbor by an earlier delta
+    fn neighbor<I>(&self, c: Coord, delta: Coord, mut earlier: I) -> Option<Coord>
+    where
+        I: Iterator<Item = Coord>,
+    {
+        let neigh = self.resolve(&(c + delta))?;
+        let repeated = self.topology == Topology::Torus
+            && (Some(neigh) == self.reindex(c) || earlier.any(|e| self.resolve(&(c + e)) == Some(neigh)));
+        (!repeated).then_some(neigh)
+    }
+
+    // Get valid neighboring points with direction to them and their value (only up, down, left, right),
+    // every distinct cell other than `c` is yielded once even on a small torus
+    pub fn neighbors(&self, c: &Coord) -> impl Iterator<Item = (Coord, Direction, &T)> {
+        const ORDER: [Direction; 4] = [Direction::Left, Direction::Up, Direction::Down, Direction::Right];
+        let c = *c;
+        ORDER.into_iter().enumerate().filter_map(move |(i, d)| {
+            let neigh = self.neighbor(c, d.to_coord(), ORDER[..i].iter().map(Direction::to_coord))?;
+            Some((neigh, d, &self[neigh]))
+        })
+    }
+
+    // Get valid neighboring points whose value satisfies the predicate (only up, down, left, right)
+    pub fn neighbors_where<P>(&self, c: &Coord, pred: P) -> impl Iterator<Item = (Coord, Direction, &T)>
+    where
+        P: Fn(&T) -> bool,
+    {
+        self.neighbors(c).filter(move |(_, _, v)| pred(v))
+    }
+
+    // Get valid neighboring points in the given neighborhood, each distinct cell once like `neighbors`
+    pub fn neighbors_in(&self, c: &Coord, neighborhood: Neighborhood) -> impl Iterator<Item = (Coord, Direction8, &T)> {
+        let c = *c;
+        let directions = neighborhood.directions();
+        directions.iter().enumerate().filter_map(move |(i, &d)| {
+            let neigh = self.neighbor(c, d.to_coord(), directions[..i].iter().map(Direction8::to_coord))?;
+            Some((neigh, d, &self[neigh]))
+        })
+    }
+
+    // Iterate over all rows from top to bottom
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1) as usize)
+    }
+
+    // Draw the grid row by row, converting each cell to a character
+    pub fn render<F>(&self, mut render_fn: F) -> String
+    where
+        F: FnMut(&T) -> char,
+    {
+        let mut out = String::with_capacity(self.cells.len() + self.height as usize);
+        for row in self.rows() {
+            out.extend(row.iter().map(&mut render_fn));
+            out.push('\n');
+        }
+        out
+    }
+}
+
+impl<T> Default for Grid<T>
+where T: PartialEq + Copy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Grid<T> 
+where T: PartialEq + Copy {
+    // Create a new grid
+    pub fn new() -> Grid<T> {
+        Grid {
+            cells: Vec::new(),
+            width: 0,
+            height: 0,
+            topology: Topology::Bounded,
+        }
+    }
+
+    // Create a grid of given size with every cell set to `value`
+    pub fn filled(w: u32, h: u32, value: T) -> Grid<T> {
+        Grid {
+            cells: vec![value; (w * h) as usize],
+            width: w as i32,
+            height: h as i32,
+            topology: Topology::Bounded,
+        }
+    }
+
+    // Read a text into a grid, parsing each cell with the given function
+    pub fn parse_with<E, F>(text: &str, mut parse_fn: F) -> Result<Grid<T>, GridParseError>
+    where
+        F: FnMut(char) -> Result<T, E>,
+        E: Into<Box<dyn Error + Send + Sync>>,
+    {
+        Grid::parse_cells(text, |c| parse_fn(c).map_err(|e| Some(e.into())))
+    }
+
+    // Read everything from a reader into a grid, parsing each cell with the given function
+    pub fn from_reader<R, E, F>(mut reader: R, parse_fn: F) -> Result<Grid<T>, GridParseError>
+    where
+        R: Read,
+        F: FnMut(char) -> Result<T, E>,
+        E: Into<Box<dyn Error + Send + Sync>>,
+    {
+        let mut text = String::new();
+        reader.read_to_string(&mut text)?;
+        Grid::parse_with(&text, parse_fn)
+    }
+
+    // Read a text into a grid, cell errors may carry the reason of failure
+    fn parse_cells<F>(text: &str, mut parse_fn: F) -> Result<Grid<T>, GridParseError>
+    where
+        F: FnMut(char) -> Result<T, Option<Box<dyn Error + Send + Sync>>>,
+    {
+        let mut cells = Vec::with_capacity(text.len());
+        let mut width = 0;
+        // Trailing empty lines are not rows
+        for (i, line) in text.trim_end_matches(['\n', '\r']).lines().enumerate() {
+            let row_start = cells.len();
+            for (j, c) in line.chars().enumerate() {
+                match parse_fn(c) {
+                    Ok(cell) => cells.push(cell),
+                    Err(source) => return Err(GridParseError::InvalidCell { line: i + 1, column: j + 1, value: c, source }),
+                }
+            }
+            // All rows must be as wide as the first one
+            let row_width = cells.len() - row_start;
+            if i == 0 {
+                width = row_width;
+            } else if row_width != width {
+                return Err(GridParseError::RaggedRow { line: i + 1, width: row_width, expected: width });
+            }
+        }
+
+        if width == 0 {
+            return Err(GridParseError::Empty);
+        }
+
+        Ok(Grid {
+            width: width as i32,
+            height: (cells.len() / width) as i32,
+            cells,
+            topology: Topology::Bounded,
+        })
+    }
+
+    // Find all occurrences of a value in the grid
+    pub fn find(&self, needle: T) -> Vec<Coord> {
+        let w = self.width;
+        self.cells.iter().enumerate().filter_map(|(i, &cell)| {
+            if cell == needle {
+                Some(Coord::from_rc(i as i32 / w, i as i32 % w))
+            } else {
+                None
+            }
+        })
+        .collect()
+    }
+
+    // First occurrence of a value row by row, e.g. the start of a maze
+    pub fn find_first(&self, needle: T) -> Option<Coord> {
+        let i = self.cells.iter().position(|&cell| cell == needle)? as i32;
+        Some(Coord::from_rc(i / self.width, i % self.width))
+    }
+
+    // Get the value at a coordinate
+    pub fn at(&self, c: &Coord) -> Option<T> {
+        self.get(c).copied()
+    }
+
+    // Set the value at a coordinate
+    pub fn set(&mut self, c: Coord, val: T) {
+        self[c] = val;
+    }
+
+    // Check if point is at the edge of the grid
+    pub fn is_edge(&self, c: &Coord) -> bool {
+        c.row == 0 || c.row == self.height - 1 || c.col == 0 || c.col == self.width - 1
+    }
+
+    // Get valid neighboring points with the same value (only up, down, left, right)
+    pub fn same_neighbors(&self, c: &Coord) -> impl Iterator<Item = (Coord, Direction, &T)> {
+        let val = self[*c];
+        self.neighbors_where(c, move |&v| v == val)
+    }
+
+    // Get valid neighboring points with the same value in the given neighborhood
+    pub fn same_neighbors_in(&self, c: &Coord, neighborhood: Neighborhood) -> impl Iterator<Item = (Coord, Direction8, &T)> {
+        let val = self[*c];
+        self.neighbors_in(c, neighborhood).filter(move |&(_, _, &v)| v == val)
+    }
+}
+
+impl<T> GridLike<T> for Grid<T>
+where T: PartialEq + Copy {
+    fn at(&self, c: &Coord) -> Option<T> {
+        Grid::at(self, c)
+    }
+
+    fn set(&mut self, c: Coord, val: T) {
+        Grid::set(self, c, val)
+    }
+
+    fn find(&self, needle: T) -> Vec<Coord> {
+        Grid::find(self, needle)
+    }
+
+    fn bounds(&self) -> Option<Rect> {
+        (self.width > 0 && self.height > 0).then(|| Rect::new(Coord::ZERO, self.width, self.height))
+    }
+
+    // Wraps around a torus
+    fn neighbors(&self, c: &Coord) -> impl Iterator<Item = (Coord, Direction, T)> {
+        Grid::neighbors(self, c).map(|(n, d, &v)| (n, d, v))
+    }
+}
+
+impl<T> Grid<T>
+where T: GridCell + PartialEq + Copy {
+    // Read a file into a grid, fails on unreadable file or invalid grid
+    pub fn try_from_file(filename: &str) -> Result<Grid<T>, GridParseError> {
+        let mut text = String::new();
+        File::open(filename)?.read_to_string(&mut text)?;
+        Grid::try_from_str(&text)
+    }
+
+    // Read a text into a grid, fails on invalid grid
+    pub fn try_from_str(text: &str) -> Result<Grid<T>, GridParseError> {
+        Grid::parse_cells(text, |c| T::from_char(c).ok_or(None))
+    }
+}
+
+impl<T> FromStr for Grid<T>
+where T: GridCell + PartialEq + Copy {
+    type Err = GridParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::try_from_str(s)
+    }
+}
+
+impl Grid<char> {
+    // Read a file into a grid of characters, panics on invalid file
+    pub fn from_file_as_chars(filename: &str) -> Grid<char> {
+        Grid::try_from_file(filename).unwrap()
+    }
+
+    pub fn from_lines(lines: Vec<&str>) -> Grid<char> {
+        Grid {
+            cells: lines.iter().flat_map(|line| line.chars()).collect(),
+            width: lines[0].chars().count() as i32,
+            height: lines.len() as i32,
+            topology: Topology::Bounded,
+        }
+    }
+
+    pub fn new_size(w: u32, h: u32) -> Grid<char> {
+        Grid::filled(w, h, '.')
+    }
+}
+
+impl Grid<i8> {
+    // Read a file into a grid of i8, panics on invalid file
+    pub fn from_file_as_i8(filename: &str) -> Grid<i8> {
+        Grid::try_from_file(filename).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Tile {
+        Open,
+        Wall,
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct UnknownTile(char);
+
+    impl fmt::Display for UnknownTile {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "unknown tile {:?}", self.0)
+        }
+    }
+
+    impl Error for UnknownTile {}
+
+    fn tile(c: char) -> Result<Tile, UnknownTile> {
+        match c {
+            '.' => Ok(Tile::Open),
+            '#' => Ok(Tile::Wall),
+            _ => Err(UnknownTile(c)),
+        }
+    }
+
+    #[test]
+    fn parse_errors() {
+        match "12\n1.".parse::<Grid<i8>>() {
+            Err(GridParseError::InvalidCell { line: 2, column: 2, value: '.', source: None }) => {}
+            other => panic!("expected an invalid cell, got {:?}", other),
+        }
+        match "ab\nabc".parse::<Grid<char>>() {
+            Err(GridParseError::RaggedRow { line: 2, width: 3, expected: 2 }) => {}
+            other => panic!("expected a ragged row, got {:?}", other),
+        }
+        assert!(matches!("".parse::<Grid<char>>(), Err(GridParseError::Empty)));
+        assert!(matches!("\n\n".parse::<Grid<char>>(), Err(GridParseError::Empty)));
+        assert!(matches!("ab\n\ncd".parse::<Grid<char>>(), Err(GridParseError::RaggedRow { line: 2, width: 0, expected: 2 })));
+
+        let grid: Grid<char> = "ab\r\ncd\r\n\r\n\n".parse().unwrap();
+        assert_eq!((grid.width, grid.height), (2, 2));
+
+        let grid: Grid<i8> = "12\n34\n".parse().unwrap();
+        assert_eq!((grid.width, grid.height), (2, 2));
+        assert_eq!(grid.cells(), [1, 2, 3, 4]);
+        assert_eq!(grid.find_first(3), Some(Coord::from_rc(1, 0)));
+        assert_eq!(grid.find_first(5), None);
+    }
+
+    #[test]
+    fn custom_cells() {
+        let grid = Grid::parse_with(".#\n#.", tile).unwrap();
+        assert_eq!(grid.cells(), [Tile::Open, Tile::Wall, Tile::Wall, Tile::Open]);
+
+        let grid = Grid::from_reader("##.\n...\n".as_bytes(), tile).unwrap();
+        assert_eq!((grid.width, grid.height), (3, 2));
+        assert_eq!(grid.at(&Coord::from_rc(0, 2)), Some(Tile::Open));
+
+        let err = Grid::from_reader(".#\n#o".as_bytes(), tile).unwrap_err();
+        assert!(matches!(err, GridParseError::InvalidCell { line: 2, column: 2, value: 'o', .. }));
+        assert_eq!(err.to_string(), "invalid cell 'o' at line 2, column 2: unknown tile 'o'");
+        let source = err.source().and_then(|e| e.downcast_ref::<UnknownTile>());
+        assert_eq!(source, Some(&UnknownTile('o')));
+    }
+
+    #[test]
+    fn neighborhoods_at_corner() {
+        let grid: Grid<char> = "ab\ncd\nef".parse().unwrap();
+        let moore: Vec<(Coord, Direction8, char)> = grid.neighbors_in(&Coord::ZERO, Neighborhood::Moore)
+            .map(|(c, d, &v)| (c, d, v))
+            .collect();
+        assert_eq!(moore, [
+            (Coord::from_rc(1, 0), Direction8::Down, 'c'),
+            (Coord::from_rc(0, 1), Direction8::Right, 'b'),
+            (Coord::from_rc(1, 1), Direction8::DownRight, 'd'),
+        ]);
+        assert_eq!(grid.neighbors_in(&Coord::ZERO, Neighborhood::VonNeumann).count(), 2);
+        assert_eq!(grid.neighbors_in(&Coord::from_rc(1, 0), Neighborhood::Moore).count(), 5);
+    }
+
+    #[test]
+    fn torus_wrapping() {
+        let grid = "abc\ndef".parse::<Grid<char>>().unwrap().with_topology(Topology::Torus);
+        assert_eq!(grid.at(&Coord::from_rc(0, 3)), Some('a'));
+        assert_eq!(grid.at(&Coord::from_rc(-1, -1)), Some('f'));
+        assert_eq!(grid.at(&Coord::from_rc(4, 7)), Some('b'));
+        assert_eq!(grid.at(&Coord::from_rc(-6, -9)), Some('a'));
+        assert_eq!(grid.move_by(&Coord::ZERO, Coord::from_rc(1, 1), 7), Some(Coord::from_rc(1, 1)));
+        assert_eq!(grid.move_by(&Coord::from_rc(1, 2), Coord::from_rc(-1, 2), 5), Some(Coord::from_rc(0, 0)));
+        assert_eq!(grid.move_by(&Coord::ZERO, Coord::from_rc(0, -1), 301), Some(Coord::from_rc(0, 2)));
+        assert_eq!(grid.move_by(&Coord::ZERO, Coord::from_rc(1, 1), i32::MAX), Some(Coord::from_rc(1, 1)));
+        assert_eq!(grid.move_by(&Coord::from_rc(1, 0), Coord::from_rc(-7, 5), i32::MAX), Some(Coord::from_rc(0, 2)));
+        assert_eq!(grid.move_by(&Coord::ZERO, Coord::from_rc(3, -4), i32::MIN), Some(Coord::from_rc(0, 2)));
+
+        let bounded = grid.clone().with_topology(Topology::Bounded);
+        assert_eq!(bounded.at(&Coord::from_rc(0, 3)), None);
+        assert_eq!(bounded.move_by(&Coord::ZERO, Coord::from_rc(0, 1), 3), None);
+        assert_eq!(bounded.move_by(&Coord::ZERO, Coord::from_rc(0, 1), 2), Some(Coord::from_rc(0, 2)));
+        assert_eq!(bounded.move_by(&Coord::ZERO, Coord::from_rc(0, 1), i32::MAX), None);
+        assert_eq!(bounded.move_by(&Coord::from_rc(1, 2), Coord::from_rc(-1, -1), i32::MIN), None);
+
+        let around: Vec<char> = grid.neighbors(&Coord::ZERO).map(|(_, _, &v)| v).collect();
+        assert_eq!(around, ['c', 'd', 'b']);
+    }
+
+    #[test]
+    fn small_torus_neighbors() {
+        let coords = |grid: &Grid<char>, c: Coord| grid.neighbors(&c).map(|(n, _, _)| n).collect::<Vec<_>>();
+
+        let line = Grid::new_size(2, 1).with_topology(Topology::Torus);
+        assert_eq!(coords(&line, Coord::ZERO), [Coord::from_rc(0, 1)]);
+        assert_eq!(coords(&line, Coord::from_rc(0, 1)), [Coord::ZERO]);
+
+        let single = Grid::new_size(1, 1).with_topology(Topology::Torus);
+        assert!(coords(&single, Coord::ZERO).is_empty());
+        assert_eq!(single.neighbors_in(&Coord::ZERO, Neighborhood::Moore).count(), 0);
+
+        let square = Grid::new_size(2, 2).with_topology(Topology::Torus);
+        assert_eq!(coords(&square, Coord::ZERO), [Coord::from_rc(0, 1), Coord::from_rc(1, 0)]);
+        assert_eq!(square.neighbors_in(&Coord::ZERO, Neighborhood::Moore).count(), 3);
+
+        let empty = Grid::<char>::new().with_topology(Topology::Torus);
+        assert_eq!(empty.at(&Coord::ZERO), None);
+        assert_eq!(empty.reindex(Coord::from_rc(3, -1)), None);
+        assert_eq!(empty.move_by(&Coord::ZERO, Coord::from_rc(1, 0), 5), None);
+        assert!(coords(&empty, Coord::ZERO).is_empty());
+        let flat = Grid::new_size(3, 0).with_topology(Topology::Torus);
+        assert_eq!(flat.at(&Coord::from_rc(0, 1)), None);
+
+        let wide = Grid::new_size(5, 2).with_topology(Topology::Torus);
+        assert_eq!(wide.neighbors(&Coord::ZERO).count(), 3);
+        assert_eq!(wide.neighbors_in(&Coord::ZERO, Neighborhood::Moore).count(), 5);
+    }
+}
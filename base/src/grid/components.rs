@@ -0,0 +1,296 @@
+use crate::Coord;
+use crate::DirSet;
+use crate::Direction;
+use crate::Direction8;
+use crate::Grid;
+use crate::Neighborhood;
+use crate::Rect;
+
+// Connected group of cells found by `Grid::components`
+#[derive(Debug, Clone)]
+pub struct Region {
+    id: usize,
+    cells: Vec<Coord>,
+    bbox: Rect,
+    // Membership of cells within the bounding box
+    mask: Grid<bool>,
+}
+
+impl Region {
+    fn new(id: usize, cells: Vec<Coord>) -> Region {
+        let bbox = Rect::bounding(&cells).unwrap();
+        let mut mask = Grid::filled(bbox.width as u32, bbox.height as u32, false);
+        for c in cells.iter() {
+            mask.set(*c - bbox.top_left, true);
+        }
+        Region { id, cells, bbox, mask }
+    }
+
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    // Cells of the region in the order they were reached
+    pub fn cells(&self) -> &[Coord] {
+        &self.cells
+    }
+
+    pub fn bounding_box(&self) -> Rect {
+        self.bbox
+    }
+
+    pub fn contains(&self, c: &Coord) -> bool {
+        self.mask.at(&(*c - self.bbox.top_left)).unwrap_or(false)
+    }
+
+    fn contains_at(&self, c: &Coord, d: Direction8) -> bool {
+        self.contains(&(*c + d.to_coord()))
+    }
+
+    // Sides of a cell where the neighbour lies outside of the region
+    pub fn fences_at(&self, c: &Coord) -> DirSet {
+        Direction::ALL.into_iter().filter(|&d| !self.contains_at(c, d.into())).collect()
+    }
+
+    // Cells with a direction in which the neighbour lies outside of the region
+    pub fn boundary_edges(&self) -> Vec<(Coord, Direction)> {
+        let mut edges = Vec::new();
+        for c in self.cells.iter() {
+            edges.extend(self.fences_at(c).iter().map(|d| (*c, d)));
+        }
+        edges
+    }
+
+    // Length of the fence around the region, including fences around holes
+    pub fn perimeter(&self) -> usize {
+        self.boundary_edges().len()
+    }
+
+    // Number of straight fence segments, an edge starts a side unless the cell
+    // to its left (looking outwards) has the same edge
+    pub fn sides(&self) -> usize {
+        self.boundary_edges()
+            .into_iter()
+            .filter(|(c, d)| {
+                let outwards = Direction8::from(*d);
+                let left = outwards.rotate(-2);
+                !self.contains_at(c, left) || self.contains_at(c, left.rotate(1))
+            })
+            .count()
+    }
+
+    // Number of convex and concave corners of the region outline
+    pub fn corners(&self) -> usize {
+        let mut corners = 0;
+        for c in self.cells.iter() {
+            for diagonal in [Direction8::UpRight, Direction8::DownRight, Direction8::DownLeft, Direction8::UpLeft] {
+                let first = self.contains_at(c, diagonal.rotate(-1));
+                let second = self.contains_at(c, diagonal.rotate(1));
+                // Outer corner, or inner corner with the diagonal cell missing
+                if (!first && !second) || (first && second && !self.contains_at(c, diagonal)) {
+                    corners += 1;
+                }
+            }
+        }
+        corners
+    }
+}
+
+// Region label of every cell of a grid
+#[derive(Debug, Clone)]
+pub struct Labels {
+    labels: Grid<usize>,
+    regions: Vec<Region>,
+}
+
+impl Labels {
+    // Label of the region at a coordinate
+    pub fn label(&self, c: &Coord) -> Option<usize> {
+        self.labels.at(c)
+    }
+
+    pub fn region(&self, id: usize) -> Option<&Region> {
+        self.regions.get(id)
+    }
+
+    pub fn region_at(&self, c: &Coord) -> Option<&Region> {
+        self.label(c).map(|id| &self.regions[id])
+    }
+
+    // Regions ordered by their label
+    pub fn regions(&self) -> &[Region] {
+        &self.regions
+    }
+
+    pub fn len(&self) -> usize {
+        self.regions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Region> {
+        self.regions.iter()
+    }
+}
+
+impl<T> Grid<T>
+where T: PartialEq + Copy {
+    // Label 4-connected regions, neighbouring cells belong together when `same` holds
+    pub fn components<F>(&self, same: F) -> Labels
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        self.components_in(Neighborhood::VonNeumann, same)
+    }
+
+    // Label regions connected through the given neighborhood
+    // Regions are flat, cells connect only within the grid even on a torus
+    pub fn components_in<F>(&self, neighborhood: Neighborhood, mut same: F) -> Labels
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        const UNLABELED: usize = usize::MAX;
+        let mut labels = Grid::filled(self.width as u32, self.height as u32, UNLABELED);
+        let mut regions = Vec::new();
+        let mut stack = Vec::new();
+
+        for start in 0..self.cells.len() as i32 {
+            let start = Coord::from_rc(start / self.width, start % self.width);
+            if labels[start] != UNLABELED {
+                continue;
+            }
+            let id = regions.len();
+            let mut cells = Vec::new();
+            labels[start] = id;
+            stack.push(start);
+            while let Some(current) = stack.pop() {
+                cells.push(current);
+                let value = &self[current];
+                for d in neighborhood.directions() {
+                    let next = current + d.to_coord();
+                    if self.valid_coords(&next) && labels[next] == UNLABELED && same(value, &self[next]) {
+                        labels[next] = id;
+                        stack.push(next);
+                    }
+                }
+            }
+            regions.push(Region::new(id, cells));
+        }
+
+        Labels { labels, regions }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Topology;
+
+    // Sum of area * sides and area * perimeter over all regions of same letters
+    fn costs(text: &str, neighborhood: Neighborhood) -> (usize, usize) {
+        let grid: Grid<char> = text.parse().unwrap();
+        grid.components_in(neighborhood, |a, b| a == b).iter().fold((0, 0), |(p, s), r| {
+            (p + r.area() * r.perimeter(), s + r.area() * r.sides())
+        })
+    }
+
+    #[test]
+    fn simple_regions() {
+        let grid: Grid<char> = "AAAA\nBBCD\nBBCC\nEEEC".parse().unwrap();
+        let labels = grid.components(|a, b| a == b);
+        assert_eq!(labels.len(), 5);
+        let c = labels.region_at(&Coord::from_rc(1, 2)).unwrap();
+        assert_eq!(c.area(), 4);
+        assert_eq!(c.perimeter(), 10);
+        assert_eq!(c.sides(), 8);
+        assert_eq!(c.corners(), 8);
+        assert_eq!(c.bounding_box(), Rect::new(Coord::from_rc(1, 2), 2, 3));
+        assert_eq!(costs("AAAA\nBBCD\nBBCC\nEEEC", Neighborhood::VonNeumann), (140, 80));
+    }
+
+    #[test]
+    fn e_shape() {
+        let text = "EEEEE\nEXXXX\nEEEEE\nEXXXX\nEEEEE";
+        let grid: Grid<char> = text.parse().unwrap();
+        let labels = grid.components(|a, b| a == b);
+        let e = labels.region_at(&Coord::from_rc(0, 0)).unwrap();
+        assert_eq!(e.area(), 17);
+        assert_eq!(e.sides(), 12);
+        assert_eq!(e.corners(), 12);
+        assert_eq!(costs(text, Neighborhood::VonNeumann).1, 236);
+    }
+
+    #[test]
+    fn nested_holes() {
+        let text = "OOOOO\nOXOXO\nOOOOO\nOXOXO\nOOOOO";
+        let grid: Grid<char> = text.parse().unwrap();
+        let labels = grid.components(|a, b| a == b);
+        assert_eq!(labels.len(), 5);
+        let o = labels.region_at(&Coord::from_rc(0, 0)).unwrap();
+        assert_eq!(o.area(), 21);
+        assert_eq!(o.perimeter(), 36);
+        assert_eq!(o.sides(), 20);
+        assert_eq!(o.boundary_edges().len(), 36);
+        assert_eq!(costs(text, Neighborhood::VonNeumann), (772, 436));
+    }
+
+    #[test]
+    fn diagonal_touching() {
+        let text = "AAAAAA\nAAABBA\nAAABBA\nABBAAA\nABBAAA\nAAAAAA";
+        let grid: Grid<char> = text.parse().unwrap();
+        let labels = grid.components(|a, b| a == b);
+        assert_eq!(labels.len(), 3);
+        let a = labels.region_at(&Coord::from_rc(0, 0)).unwrap();
+        assert_eq!(a.sides(), 12);
+        assert_eq!(a.corners(), 12);
+        assert_eq!(costs(text, Neighborhood::VonNeumann).1, 368);
+
+        // Both B blocks form a single region when diagonals connect
+        let labels = grid.components_in(Neighborhood::Moore, |a, b| a == b);
+        assert_eq!(labels.len(), 2);
+        let b = labels.region_at(&Coord::from_rc(1, 3)).unwrap();
+        assert_eq!(b.area(), 8);
+        assert_eq!(b.perimeter(), 16);
+        assert_eq!(b.sides(), 8);
+        assert_eq!(b.corners(), 8);
+    }
+
+    #[test]
+    fn larger_example() {
+        let text = "RRRRIICCFF\nRRRRIICCCF\nVVRRRCCFFF\nVVRCCCJFFF\nVVVVCJJCFE\n\
+                    VVIVCCJJEE\nVVIIICJJEE\nMIIIIIJJEE\nMIIISIJEEE\nMMMISSJEEE";
+        assert_eq!(costs(text, Neighborhood::VonNeumann), (1930, 1206));
+    }
+
+    #[test]
+    fn flat_regions_on_torus() {
+        for text in ["ab\nab", "aba\nbbb", "AAAA\nBBCD\nBBCC\nEEEC"] {
+            let bounded: Grid<char> = text.parse().unwrap();
+            let torus = bounded.clone().with_topology(Topology::Torus);
+            let flat = bounded.components(|a, b| a == b);
+            let wrapped = torus.components(|a, b| a == b);
+            assert_eq!(flat.len(), wrapped.len(), "{:?}", text);
+            for (f, w) in flat.iter().zip(wrapped.iter()) {
+                assert_eq!(f.cells(), w.cells());
+                assert_eq!((f.perimeter(), f.sides(), f.corners()), (w.perimeter(), w.sides(), w.corners()));
+            }
+        }
+
+        // Cells at opposite edges are not joined by wrapping around
+        let grid = "aba\nbbb".parse::<Grid<char>>().unwrap().with_topology(Topology::Torus);
+        let labels = grid.components(|a, b| a == b);
+        assert_eq!(labels.len(), 3);
+        assert_ne!(labels.label(&Coord::from_rc(0, 0)), labels.label(&Coord::from_rc(0, 2)));
+        assert_eq!(labels.region_at(&Coord::from_rc(0, 2)).unwrap().perimeter(), 4);
+        assert_eq!(labels.region_at(&Coord::from_rc(1, 0)).unwrap().perimeter(), 10);
+
+        let column = "ab\nab".parse::<Grid<char>>().unwrap().with_topology(Topology::Torus);
+        assert_eq!(column.components(|a, b| a == b).region_at(&Coord::ZERO).unwrap().perimeter(), 6);
+    }
+}
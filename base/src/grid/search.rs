@@ -0,0 +1,107 @@
+use crate::Coord;
+use crate::Direction8;
+use crate::Grid;
+
+impl<T> Grid<T>
+where T: PartialEq + Copy {
+    // Top left corners of all places where `pattern` fits, None cells of the pattern match anything
+    // An empty pattern is found nowhere
+    pub fn find_pattern(&self, pattern: &Grid<Option<T>>) -> Vec<Coord> {
+        let mut result = Vec::new();
+        if pattern.width == 0 || pattern.height == 0 {
+            return result;
+        }
+        for i in 0..=(self.height - pattern.height) {
+            for j in 0..=(self.width - pattern.width) {
+                let anchor = Coord::from_rc(i, j);
+                if self.pattern_at(pattern, anchor) {
+                    result.push(anchor);
+                }
+            }
+        }
+        result
+    }
+
+    fn pattern_at(&self, pattern: &Grid<Option<T>>, anchor: Coord) -> bool {
+        (0..pattern.height).all(|i| {
+            (0..pattern.width).all(|j| match pattern[Coord::from_rc(i, j)] {
+                Some(v) => self[anchor + Coord::from_rc(i, j)] == v,
+                None => true,
+            })
+        })
+    }
+
+    // Starting cells and directions in which the grid reads `sequence`
+    pub fn find_sequence(&self, sequence: &[T], directions: &[Direction8]) -> Vec<(Coord, Direction8)> {
+        let mut result = Vec::new();
+        let Some(first) = sequence.first() else {
+            return result;
+        };
+        for anchor in self.find(*first) {
+            for &dir in directions {
+                if self.sequence_at(sequence, anchor, dir) {
+                    result.push((anchor, dir));
+                }
+            }
+        }
+        result
+    }
+
+    fn sequence_at(&self, sequence: &[T], anchor: Coord, dir: Direction8) -> bool {
+        let step = dir.to_coord();
+        sequence.iter().enumerate().all(|(k, v)| self.at(&(anchor + step * k as i32)) == Some(*v))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::Infallible;
+
+    const WORDS: &str = "MMMSXXMASM\nMSAMXMSMSA\nAMXSXMAAMM\nMSAMASMSMX\nXMASAMXAMM\n\
+                         XXAMMXXAMA\nSMSMSASXSS\nSAXAMASAAA\nMAMMMXMMMM\nMXMXAXMASX";
+
+    // Pattern with '.' matching any cell
+    fn pattern(text: &str) -> Grid<Option<char>> {
+        Grid::parse_with(text, |c| Ok::<_, Infallible>((c != '.').then_some(c))).unwrap()
+    }
+
+    #[test]
+    fn word_search() {
+        let grid: Grid<char> = WORDS.parse().unwrap();
+        let xmas: Vec<char> = "XMAS".chars().collect();
+        assert_eq!(grid.find_sequence(&xmas, &Direction8::ALL).len(), 18);
+
+        let found: Vec<Coord> = grid.find_sequence(&xmas, &[Direction8::Right]).into_iter().map(|(c, _)| c).collect();
+        assert_eq!(found, vec![Coord::from_rc(0, 5), Coord::from_rc(4, 0), Coord::from_rc(9, 5)]);
+        assert!(grid.find_sequence(&[], &Direction8::ALL).is_empty());
+    }
+
+    #[test]
+    fn cross_pattern() {
+        let grid: Grid<char> = WORDS.parse().unwrap();
+        let mut cross = pattern("M.S\n.A.\nM.S");
+        let mut count = 0;
+        for _ in 0..4 {
+            count += grid.find_pattern(&cross).len();
+            cross = cross.rotate_cw();
+        }
+        assert_eq!(count, 9);
+    }
+
+    #[test]
+    fn pattern_larger_than_grid() {
+        let grid: Grid<char> = "ab\ncd".parse().unwrap();
+        assert!(grid.find_pattern(&pattern("abc\ncd.")).is_empty());
+        assert!(grid.find_pattern(&pattern("a.\ncd\n..")).is_empty());
+        assert_eq!(grid.find_pattern(&pattern(".d")), vec![Coord::from_rc(1, 0)]);
+    }
+
+    #[test]
+    fn empty_pattern() {
+        let grid: Grid<char> = "ab\ncd".parse().unwrap();
+        assert!(grid.find_pattern(&Grid::new()).is_empty());
+        assert!(grid.find_pattern(&Grid::filled(0, 2, None)).is_empty());
+        assert!(grid.find_pattern(&Grid::filled(2, 0, Some('a'))).is_empty());
+    }
+}
@@ -0,0 +1,194 @@
+use crate::Coord;
+use crate::Grid;
+
+// Rectangle of cells given by its top left corner and size
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash)]
+pub struct Rect {
+    pub top_left: Coord,
+    pub width: i32,
+    pub height: i32,
+}
+
+impl Rect {
+    pub fn new(top_left: Coord, width: i32, height: i32) -> Rect {
+        Rect { top_left, width, height }
+    }
+
+    // Smallest rectangle containing both corners
+    pub fn from_corners(a: Coord, b: Coord) -> Rect {
+        let top_left = Coord::from_rc(a.row.min(b.row), a.col.min(b.col));
+        Rect {
+            top_left,
+            width: a.col.max(b.col) - top_left.col + 1,
+            height: a.row.max(b.row) - top_left.row + 1,
+        }
+    }
+
+    // Smallest rectangle containing all coordinates, None if there are none
+    pub fn bounding<'a, I>(coords: I) -> Option<Rect>
+    where
+        I: IntoIterator<Item = &'a Coord>,
+    {
+        let mut coords = coords.into_iter();
+        let first = *coords.next()?;
+        let (top_left, bottom_right) = coords.fold((first, first), |(tl, br), c| {
+            (Coord::from_rc(tl.row.min(c.row), tl.col.min(c.col)), Coord::from_rc(br.row.max(c.row), br.col.max(c.col)))
+        });
+        Some(Rect::from_corners(top_left, bottom_right))
+    }
+
+    // Bottom right corner, still inside the rectangle
+    pub fn bottom_right(&self) -> Coord {
+        self.top_left + Coord::from_rc(self.height - 1, self.width - 1)
+    }
+
+    pub fn contains(&self, c: &Coord) -> bool {
+        let br = self.bottom_right();
+        c.row >= self.top_left.row && c.col >= self.top_left.col && c.row <= br.row && c.col <= br.col
+    }
+}
+
+impl<T> Grid<T>
+where T: Copy {
+    // Build a grid of given size with the same topology, `cell_fn` gets coordinates of the new grid
+    fn build<F>(&self, w: i32, h: i32, mut cell_fn: F) -> Grid<T>
+    where
+        F: FnMut(i32, i32) -> T,
+    {
+        let mut cells = Vec::with_capacity((w * h) as usize);
+        for i in 0..h {
+            for j in 0..w {
+                cells.push(cell_fn(i, j));
+            }
+        }
+        Grid { cells, width: w, height: h, topology: self.topology }
+    }
+
+    // Swap rows and columns
+    pub fn transpose(&self) -> Grid<T> {
+        self.build(self.height, self.width, |i, j| self[Coord::from_rc(j, i)])
+    }
+
+    // Rotate clockwise by 90 degrees
+    pub fn rotate_cw(&self) -> Grid<T> {
+        self.build(self.height, self.width, |i, j| self[Coord::from_rc(self.height - 1 - j, i)])
+    }
+
+    // Rotate counter-clockwise by 90 degrees
+    pub fn rotate_ccw(&self) -> Grid<T> {
+        self.build(self.height, self.width, |i, j| self[Coord::from_rc(j, self.width - 1 - i)])
+    }
+
+    // Mirror left to right
+    pub fn flip_h(&self) -> Grid<T> {
+        self.build(self.width, self.height, |i, j| self[Coord::from_rc(i, self.width - 1 - j)])
+    }
+
+    // Mirror top to bottom
+    pub fn flip_v(&self) -> Grid<T> {
+        self.build(self.width, self.height, |i, j| self[Coord::from_rc(self.height - 1 - i, j)])
+    }
+
+    // Copy part of the grid, None if the rectangle does not fit into the grid
+    pub fn subgrid(&self, rect: &Rect) -> Option<Grid<T>> {
+        if rect.width <= 0 || rect.height <= 0 || !self.valid_coords(&rect.top_left) || !self.valid_coords(&rect.bottom_right()) {
+            return None;
+        }
+        Some(self.build(rect.width, rect.height, |i, j| self[rect.top_left + Coord::from_rc(i, j)]))
+    }
+
+    // Surround the grid with `n` cells of `fill` on every side
+    pub fn pad(&self, n: u32, fill: T) -> Grid<T> {
+        let n = n as i32;
+        self.build(self.width + 2 * n, self.height + 2 * n, |i, j| {
+            let c = Coord::from_rc(i - n, j - n);
+            if self.valid_coords(&c) { self[c] } else { fill }
+        })
+    }
+
+    // Widen the grid by replacing each cell with `k` cells produced by `mapping`
+    pub fn scale_x<U, I, F>(&self, k: usize, mut mapping: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> I,
+        I: IntoIterator<Item = U>,
+    {
+        let mut cells = Vec::with_capacity(self.cells.len() * k);
+        for cell in self.cells.iter() {
+            let before = cells.len();
+            cells.extend(mapping(cell));
+            assert_eq!(cells.len() - before, k, "Mapping must produce exactly {} cells", k);
+        }
+        Grid {
+            cells,
+            width: self.width * k as i32,
+            height: self.height,
+            topology: self.topology,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        "ab\ncd\nef".parse().unwrap()
+    }
+
+    fn text(grid: &Grid<char>) -> String {
+        grid.render(|&c| c)
+    }
+
+    #[test]
+    fn rotations() {
+        let g = grid();
+        assert_eq!(text(&g.transpose()), "ace\nbdf\n");
+        assert_eq!(text(&g.rotate_cw()), "eca\nfdb\n");
+        assert_eq!(text(&g.rotate_ccw()), "bdf\nace\n");
+        assert_eq!((g.rotate_cw().width, g.rotate_cw().height), (3, 2));
+        assert_eq!(text(&g.rotate_cw().rotate_ccw()), text(&g));
+        assert_eq!(text(&g.rotate_cw().rotate_cw().rotate_cw().rotate_cw()), text(&g));
+        assert_eq!(text(&g.transpose().transpose()), text(&g));
+    }
+
+    #[test]
+    fn flips() {
+        let g = grid();
+        assert_eq!(text(&g.flip_h()), "ba\ndc\nfe\n");
+        assert_eq!(text(&g.flip_v()), "ef\ncd\nab\n");
+        // Flipping both ways is a rotation by 180 degrees
+        assert_eq!(text(&g.flip_h().flip_v()), text(&g.rotate_cw().rotate_cw()));
+    }
+
+    #[test]
+    fn subgrids() {
+        let g = grid();
+        assert_eq!(text(&g.subgrid(&Rect::new(Coord::from_rc(1, 0), 2, 2)).unwrap()), "cd\nef\n");
+        assert_eq!(text(&g.subgrid(&Rect::new(Coord::from_rc(2, 1), 1, 1)).unwrap()), "f\n");
+        assert!(g.subgrid(&Rect::new(Coord::from_rc(0, 0), 0, 2)).is_none());
+        assert!(g.subgrid(&Rect::new(Coord::from_rc(0, 0), 2, 0)).is_none());
+        assert!(g.subgrid(&Rect::new(Coord::from_rc(1, 1), 2, 1)).is_none());
+        assert!(g.subgrid(&Rect::new(Coord::from_rc(2, 0), 2, 2)).is_none());
+        assert!(g.subgrid(&Rect::new(Coord::from_rc(-1, 0), 2, 2)).is_none());
+    }
+
+    #[test]
+    fn padding_and_scaling() {
+        let g = grid();
+        assert_eq!(text(&g.pad(1, '.')), "....\n.ab.\n.cd.\n.ef.\n....\n");
+        assert_eq!(text(&g.pad(0, '.')), text(&g));
+        assert_eq!(text(&g.scale_x(2, |&c| [c, c.to_ascii_uppercase()])), "aAbB\ncCdD\neEfF\n");
+    }
+
+    #[test]
+    fn rectangles() {
+        let r = Rect::from_corners(Coord::from_rc(3, 1), Coord::from_rc(1, 4));
+        assert_eq!(r, Rect::new(Coord::from_rc(1, 1), 4, 3));
+        assert_eq!(r.bottom_right(), Coord::from_rc(3, 4));
+        assert!(r.contains(&Coord::from_rc(2, 4)));
+        assert!(!r.contains(&Coord::from_rc(0, 2)));
+        let coords = [Coord::from_rc(3, 1), Coord::from_rc(1, 4), Coord::from_rc(2, 2)];
+        assert_eq!(Rect::bounding(&coords), Some(r));
+        assert_eq!(Rect::bounding(&[]), None);
+    }
+}
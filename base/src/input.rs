@@ -0,0 +1,148 @@
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+// Environment variable overriding the directory with puzzle inputs
+pub const DATA_DIR_VAR: &str = "AOC_DATA_DIR";
+
+// Which of the stored inputs of a day to use, examples are named e.g. `example1`
+#[derive(Debug, PartialEq, Clone, Eq, Hash, Default)]
+pub enum Variant {
+    #[default]
+    Real,
+    Named(String),
+}
+
+impl Variant {
+    // Name of the file holding the input
+    pub fn file_name(&self) -> &str {
+        match self {
+            Variant::Real => "input",
+            Variant::Named(name) => name,
+        }
+    }
+}
+
+impl fmt::Display for Variant {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Variant::Real => write!(f, "real"),
+            Variant::Named(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+impl FromStr for Variant {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Variant, String> {
+        match s {
+            "real" | "input" => Ok(Variant::Real),
+            "" => Err("variant name is empty".to_string()),
+            _ if s.contains(['/', '\\', '.']) => Err(format!("invalid variant name {:?}", s)),
+            _ => Ok(Variant::Named(s.to_string())),
+        }
+    }
+}
+
+// Error of reading an input, remembers where it was read from
+#[derive(Debug)]
+pub struct InputError {
+    pub source_name: String,
+    pub error: io::Error,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "failed to read {}: {}", self.source_name, self.error)
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+// Read a whole file, `-` reads the standard input
+pub fn read_path(path: &Path) -> Result<String, InputError> {
+    let result = if path == Path::new("-") {
+        io::read_to_string(io::stdin())
+    } else {
+        fs::read_to_string(path)
+    };
+    result.map_err(|error| InputError {
+        source_name: if path == Path::new("-") { "standard input".to_string() } else { path.display().to_string() },
+        error,
+    })
+}
+
+// Finds inputs stored as `<data dir>/<year>/<day>/<variant>`
+#[derive(Debug, PartialEq, Clone, Eq)]
+pub struct InputLoader {
+    data_dir: PathBuf,
+}
+
+impl InputLoader {
+    pub fn new(data_dir: impl Into<PathBuf>) -> InputLoader {
+        InputLoader { data_dir: data_dir.into() }
+    }
+
+    // Use the directory from `AOC_DATA_DIR` if it is set, otherwise the default one
+    pub fn from_env(default: impl Into<PathBuf>) -> InputLoader {
+        match std::env::var_os(DATA_DIR_VAR) {
+            Some(dir) => InputLoader::new(dir),
+            None => InputLoader::new(default),
+        }
+    }
+
+    pub fn data_dir(&self) -> &Path {
+        &self.data_dir
+    }
+
+    pub fn path(&self, year: u16, day: u8, variant: &Variant) -> PathBuf {
+        self.data_dir
+            .join(year.to_string())
+            .join(day.to_string())
+            .join(variant.file_name())
+    }
+
+    pub fn load(&self, year: u16, day: u8, variant: &Variant) -> Result<String, InputError> {
+        read_path(&self.path(year, day, variant))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn variant_names() {
+        assert_eq!("real".parse(), Ok(Variant::Real));
+        assert_eq!("input".parse(), Ok(Variant::Real));
+        assert_eq!("example1".parse(), Ok(Variant::Named("example1".to_string())));
+        for name in ["", "a/b", "a\\b", "a.b", "..", "../input"] {
+            assert!(name.parse::<Variant>().is_err(), "{:?}", name);
+        }
+
+        assert_eq!(Variant::Real.file_name(), "input");
+        assert_eq!(Variant::Real.to_string(), "real");
+        assert_eq!(Variant::Named("example2".to_string()).file_name(), "example2");
+    }
+
+    #[test]
+    fn loader_paths() {
+        let loader = InputLoader::new("data");
+        assert_eq!(loader.data_dir(), Path::new("data"));
+        assert_eq!(loader.path(2024, 16, &Variant::Real), Path::new("data/2024/16/input"));
+        assert_eq!(loader.path(2024, 6, &"example1".parse().unwrap()), Path::new("data/2024/6/example1"));
+
+        let err = InputLoader::new("missing").load(2024, 1, &Variant::Real).unwrap_err();
+        assert_eq!(err.error.kind(), io::ErrorKind::NotFound);
+        assert_eq!(err.source_name, Path::new("missing/2024/1/input").display().to_string());
+    }
+}
@@ -0,0 +1,31 @@
+mod coord;
+mod direction;
+mod grid;
+mod solution;
+mod sparse;
+
+pub mod input;
+pub mod parse;
+pub mod pathfinding;
+
+pub use coord::Coord;
+pub use direction::all_directions;
+pub use direction::DirSet;
+pub use direction::Direction;
+pub use direction::DirectionParseError;
+pub use direction::Direction8;
+pub use direction::Neighborhood;
+pub use grid::Grid;
+pub use grid::GridCell;
+pub use grid::GridLike;
+pub use grid::GridParseError;
+pub use grid::Labels;
+pub use grid::Rect;
+pub use grid::Region;
+pub use grid::Topology;
+pub use solution::parse_param;
+pub use solution::Answer;
+pub use solution::Params;
+pub use solution::ParseResult;
+pub use solution::Solution;
+pub use sparse::SparseGrid;
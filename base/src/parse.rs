@@ -0,0 +1,245 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+// Failure to read puzzle text, with the (1-based) line where it happened if known
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> ParseError {
+        ParseError { line: None, message: message.into() }
+    }
+
+    // Attach a line number unless the error already has one
+    pub fn at(self, line: usize) -> ParseError {
+        ParseError { line: self.line.or(Some(line)), ..self }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "line {}: {}", line, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+// Parse a whole value, surrounding whitespace is ignored
+pub fn value<T>(text: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    let text = text.trim();
+    text.parse().map_err(|e| ParseError::new(format!("invalid value {:?}: {}", text, e)))
+}
+
+// All integers in a text, a minus sign directly before digits makes them negative
+// For types without negative values (e.g. u32) a minus sign is only a separator, as in "1-2"
+pub fn ints<T>(text: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    let signed = "-1".parse::<T>().is_ok();
+    let bytes = text.as_bytes();
+    let mut result = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let negative = signed && bytes[i] == b'-' && bytes.get(i + 1).is_some_and(u8::is_ascii_digit);
+        if !negative && !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        i += 1;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        result.push(value(&text[start..i])?);
+    }
+    Ok(result)
+}
+
+// Exactly `N` integers of a text
+pub fn ints_n<T, const N: usize>(text: &str) -> Result<[T; N], ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    let found = ints(text)?;
+    let count = found.len();
+    found.try_into().map_err(|_| ParseError::new(format!("expected {} integers, found {}", N, count)))
+}
+
+// Value following `key` up to the next whitespace, e.g. `kv("p=0,4 v=3,-3", "v=")` is "3,-3"
+// The key has to start the text or follow whitespace, so "p=" does not match inside "xp=1"
+pub fn kv<'a>(text: &'a str, key: &str) -> Result<&'a str, ParseError> {
+    let start = text.match_indices(key)
+        .map(|(i, _)| i)
+        .find(|&i| text[..i].chars().next_back().is_none_or(char::is_whitespace))
+        .ok_or(ParseError::new(format!("missing {:?}", key)))? + key.len();
+    let rest = text[start..].trim_start();
+    Ok(rest.split_whitespace().next().unwrap_or("").trim_end_matches([',', ';']))
+}
+
+// Single line of puzzle text and its (1-based) number
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    // Errors of a line are reported with its number
+    pub fn context<T, E: fmt::Display>(&self, result: Result<T, E>) -> Result<T, ParseError> {
+        result.map_err(|e| self.error(e.to_string()))
+    }
+
+    pub fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError::new(message).at(self.number)
+    }
+
+    pub fn value<T>(&self) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        value(self.text).map_err(|e| e.at(self.number))
+    }
+
+    pub fn ints<T>(&self) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        ints(self.text).map_err(|e| e.at(self.number))
+    }
+
+    pub fn ints_n<T, const N: usize>(&self) -> Result<[T; N], ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        ints_n(self.text).map_err(|e| e.at(self.number))
+    }
+
+    pub fn kv(&self, key: &str) -> Result<&'a str, ParseError> {
+        kv(self.text, key).map_err(|e| e.at(self.number))
+    }
+}
+
+// Lines of a text numbered from `first`
+fn numbered(text: &str, first: usize) -> impl Iterator<Item = Line<'_>> {
+    text.lines().enumerate().map(move |(i, text)| Line { number: first + i, text })
+}
+
+// Lines of a text with their numbers
+pub fn lines(text: &str) -> impl Iterator<Item = Line<'_>> {
+    numbered(text, 1)
+}
+
+// Section of puzzle text separated from others by blank lines
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Block<'a> {
+    pub first_line: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Block<'a> {
+    // Lines of the block numbered as in the whole text
+    pub fn lines(&self) -> impl Iterator<Item = Line<'a>> {
+        numbered(self.text, self.first_line)
+    }
+
+    // Errors of a block are reported with its first line
+    pub fn context<T, E: fmt::Display>(&self, result: Result<T, E>) -> Result<T, ParseError> {
+        result.map_err(|e| ParseError::new(e.to_string()).at(self.first_line))
+    }
+}
+
+// Sections of a text separated by blank (or whitespace only) lines
+pub fn blocks(text: &str) -> Vec<Block<'_>> {
+    let mut result = Vec::new();
+    let mut start = None;
+    let mut offset = 0;
+    for (i, line) in text.split_inclusive('\n').enumerate() {
+        if line.trim().is_empty() {
+            if let Some((first_line, begin)) = start.take() {
+                result.push(Block { first_line, text: text[begin..offset].trim_end() });
+            }
+        } else if start.is_none() {
+            start = Some((i + 1, offset));
+        }
+        offset += line.len();
+    }
+    if let Some((first_line, begin)) = start {
+        result.push(Block { first_line, text: text[begin..].trim_end() });
+    }
+    result
+}
+
+// Exactly `N` blocks of a text
+pub fn blocks_n<const N: usize>(text: &str) -> Result<[Block<'_>; N], ParseError> {
+    let found = blocks(text);
+    let count = found.len();
+    found.try_into().map_err(|_| ParseError::new(format!("expected {} sections separated by blank lines, found {}", N, count)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers() {
+        assert_eq!(ints::<i64>("p=0,4 v=3,-3").unwrap(), vec![0, 4, 3, -3]);
+        assert_eq!(ints::<u32>("Button A: X+94, Y+34").unwrap(), vec![94, 34]);
+        assert_eq!(ints::<i32>("1-2 - 3").unwrap(), vec![1, -2, 3]);
+        assert!(ints::<u8>("300").is_err());
+        assert_eq!(ints::<u8>("-3").unwrap(), vec![3]);
+        assert_eq!(ints::<u32>("range 1-2").unwrap(), vec![1, 2]);
+        assert_eq!(ints::<i64>("range 1-2").unwrap(), vec![1, -2]);
+        assert_eq!(ints_n::<i32, 2>("Prize: X=8400, Y=5400").unwrap(), [8400, 5400]);
+        assert_eq!(ints_n::<i32, 3>("1, 2").unwrap_err().message, "expected 3 integers, found 2");
+    }
+
+    #[test]
+    fn keys() {
+        assert_eq!(kv("p=0,4 v=3,-3", "p=").unwrap(), "0,4");
+        assert_eq!(kv("p=0,4 v=3,-3", "v=").unwrap(), "3,-3");
+        assert_eq!(kv("Register A: 729", "Register A:").unwrap(), "729");
+        assert!(kv("p=0,4", "v=").is_err());
+        assert_eq!(kv("xp=1 p=2", "p=").unwrap(), "2");
+        assert_eq!(kv("xp=1\tp=2", "p=").unwrap(), "2");
+        assert!(kv("xp=1 yp=2", "p=").is_err());
+    }
+
+    #[test]
+    fn sections() {
+        let text = "a\nb\n\n\nc\n \nd\ne\n";
+        let found = blocks(text);
+        assert_eq!(found.len(), 3);
+        assert_eq!(found[0], Block { first_line: 1, text: "a\nb" });
+        assert_eq!(found[1], Block { first_line: 5, text: "c" });
+        assert_eq!(found[2], Block { first_line: 7, text: "d\ne" });
+        let numbers: Vec<usize> = found[2].lines().map(|l| l.number).collect();
+        assert_eq!(numbers, vec![7, 8]);
+        assert!(blocks_n::<2>(text).is_err());
+        assert_eq!(blocks("\r\nx\r\n\r\ny")[1], Block { first_line: 4, text: "y" });
+    }
+
+    #[test]
+    fn line_numbers() {
+        let text = "1\n2\nx\n";
+        let error = lines(text).map(|l| l.value::<u32>()).collect::<Result<Vec<_>, _>>().unwrap_err();
+        assert_eq!(error.line, Some(3));
+        assert!(error.to_string().starts_with("line 3: invalid value \"x\""));
+    }
+}
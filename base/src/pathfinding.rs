@@ -0,0 +1,400 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::hash::Hash;
+use std::ops::Add;
+
+// Cost of an edge, `Default` is the zero cost
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<C> Cost for C
+where C: Copy + Ord + Add<Output = C> + Default {}
+
+// Shortest distances from the start and the predecessor of every reached node
+#[derive(Debug, Clone)]
+pub struct Paths<N, C> {
+    start: N,
+    dist: HashMap<N, C>,
+    parents: HashMap<N, N>,
+}
+
+impl<N, C> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy,
+{
+    pub fn start(&self) -> &N {
+        &self.start
+    }
+
+    // Length of the shortest path to a node, None if it was not reached
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.dist.get(node).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<N, C> {
+        &self.dist
+    }
+
+    pub fn is_reachable(&self, node: &N) -> bool {
+        self.dist.contains_key(node)
+    }
+
+    // Node preceding `node` on its shortest path
+    pub fn predecessor(&self, node: &N) -> Option<&N> {
+        self.parents.get(node)
+    }
+
+    pub fn predecessors(&self) -> &HashMap<N, N> {
+        &self.parents
+    }
+
+    // Shortest path from the start to `goal`, both included
+    pub fn path_to(&self, goal: &N) -> Option<Vec<N>> {
+        reconstruct_path(&self.parents, &self.start, goal)
+    }
+}
+
+// Shortest distances from the start with all predecessors lying on some shortest path
+// Edge costs must be positive so that predecessors form a DAG
+#[derive(Debug, Clone)]
+pub struct PathDag<N, C> {
+    start: N,
+    dist: HashMap<N, C>,
+    parents: HashMap<N, Vec<N>>,
+}
+
+impl<N, C> PathDag<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord,
+{
+    pub fn start(&self) -> &N {
+        &self.start
+    }
+
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.dist.get(node).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<N, C> {
+        &self.dist
+    }
+
+    // All nodes preceding `node` on one of its shortest paths
+    pub fn predecessors(&self, node: &N) -> &[N] {
+        self.parents.get(node).map_or(&[], |p| p.as_slice())
+    }
+
+    // Goals with the lowest distance, the others are not reached by an optimal path
+    fn optimal_goals<'a>(&self, goals: &'a [N]) -> Vec<&'a N> {
+        let best = goals.iter().filter_map(|g| self.distance(g)).min();
+        goals.iter().filter(|g| best.is_some() && self.distance(g) == best).collect()
+    }
+
+    // Nodes lying on a shortest path to the nearest of the goals
+    pub fn nodes_on_any_optimal_path(&self, goals: &[N]) -> HashSet<N> {
+        let mut nodes = HashSet::new();
+        let mut stack: Vec<&N> = self.optimal_goals(goals);
+        while let Some(node) = stack.pop() {
+            if nodes.insert(node.clone()) {
+                stack.extend(self.predecessors(node));
+            }
+        }
+        nodes
+    }
+
+    // Number of different shortest paths to the nearest of the goals
+    pub fn count_optimal_paths(&self, goals: &[N]) -> u64 {
+        let mut nodes: Vec<N> = self.nodes_on_any_optimal_path(goals).into_iter().collect();
+        nodes.sort_by_key(|n| self.dist[n]);
+
+        // Predecessors are closer to the start, so their counts are already known
+        let mut counts: HashMap<&N, u64> = HashMap::new();
+        for node in nodes.iter() {
+            let count = if *node == self.start {
+                1
+            } else {
+                self.predecessors(node).iter().map(|p| counts[p]).sum()
+            };
+            counts.insert(node, count);
+        }
+        self.optimal_goals(goals).into_iter().map(|g| counts[g]).sum()
+    }
+
+    // Up to `limit` shortest paths to the nearest of the goals, each from the start to the goal
+    pub fn enumerate_optimal_paths(&self, goals: &[N], limit: usize) -> Vec<Vec<N>> {
+        let mut paths = Vec::new();
+        // Partial paths are built backwards from the goal
+        let mut stack: Vec<Vec<N>> = self.optimal_goals(goals).into_iter().map(|g| vec![g.clone()]).collect();
+        while let Some(path) = stack.pop() {
+            if paths.len() >= limit {
+                break;
+            }
+            let last = &path[path.len() - 1];
+            if *last == self.start {
+                let mut path = path;
+                path.reverse();
+                paths.push(path);
+                continue;
+            }
+            for p in self.predecessors(last) {
+                let mut next = path.clone();
+                next.push(p.clone());
+                stack.push(next);
+            }
+        }
+        paths
+    }
+}
+
+// Follow predecessors back from `goal` to `start` and return the path from start to goal
+pub fn reconstruct_path<N>(parents: &HashMap<N, N>, start: &N, goal: &N) -> Option<Vec<N>>
+where N: Clone + Eq + Hash {
+    let mut path = vec![goal.clone()];
+    let mut current = goal;
+    while current != start {
+        current = parents.get(current)?;
+        path.push(current.clone());
+    }
+    path.reverse();
+    Some(path)
+}
+
+// Breadth-first search where every step costs 1
+pub fn bfs<N, I, F>(start: N, mut successors: F) -> Paths<N, usize>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut dist = HashMap::new();
+    let mut parents = HashMap::new();
+    let mut queue = VecDeque::new();
+    dist.insert(start.clone(), 0);
+    queue.push_back(start.clone());
+
+    while let Some(node) = queue.pop_front() {
+        let d = dist[&node];
+        for next in successors(&node) {
+            if !dist.contains_key(&next) {
+                dist.insert(next.clone(), d + 1);
+                parents.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+
+    Paths { start, dist, parents }
+}
+
+// Dijkstra's algorithm, explores every node reachable from the start
+pub fn dijkstra<N, C, I, F>(start: N, successors: F) -> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+{
+    search(start, successors, |_| C::default(), |_| false).0
+}
+
+// Dijkstra's algorithm keeping every predecessor on a shortest path
+pub fn dijkstra_all<N, C, I, F>(start: N, mut successors: F) -> PathDag<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut dist = HashMap::new();
+    let mut parents: HashMap<N, Vec<N>> = HashMap::new();
+    let mut queue = BinaryHeap::new();
+    dist.insert(start.clone(), C::default());
+    queue.push(Entry { priority: C::default(), cost: C::default(), node: start.clone() });
+
+    while let Some(Entry { cost, node, .. }) = queue.pop() {
+        if cost > dist[&node] {
+            continue;
+        }
+        for (next, step) in successors(&node) {
+            let new_cost = cost + step;
+            match dist.get(&next) {
+                Some(&d) if new_cost > d => (),
+                Some(&d) if new_cost == d => parents.entry(next).or_default().push(node.clone()),
+                _ => {
+                    dist.insert(next.clone(), new_cost);
+                    parents.insert(next.clone(), vec![node.clone()]);
+                    queue.push(Entry { priority: new_cost, cost: new_cost, node: next });
+                }
+            }
+        }
+    }
+
+    PathDag { start, dist, parents }
+}
+
+// A* search until the first node satisfying `goal`, returns the path to it and its cost
+// The heuristic must never overestimate the remaining cost
+pub fn astar<N, C, I, F, H, G>(start: N, successors: F, heuristic: H, goal: G) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    H: FnMut(&N) -> C,
+    G: FnMut(&N) -> bool,
+{
+    let (paths, reached) = search(start, successors, heuristic, goal);
+    let reached = reached?;
+    Some((paths.path_to(&reached)?, paths.distance(&reached)?))
+}
+
+// Entry of the priority queue, ordered so that the lowest priority is popped first
+struct Entry<N, C> {
+    priority: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> PartialEq for Entry<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for Entry<N, C> {}
+
+impl<N, C: Ord> PartialOrd for Entry<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for Entry<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+// Best-first search shared by Dijkstra and A*, stops when a goal node is popped
+fn search<N, C, I, F, H, G>(start: N, mut successors: F, mut heuristic: H, mut goal: G) -> (Paths<N, C>, Option<N>)
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    H: FnMut(&N) -> C,
+    G: FnMut(&N) -> bool,
+{
+    let mut dist = HashMap::new();
+    let mut parents = HashMap::new();
+    let mut queue = BinaryHeap::new();
+    dist.insert(start.clone(), C::default());
+    queue.push(Entry { priority: heuristic(&start), cost: C::default(), node: start.clone() });
+
+    let mut reached = None;
+    while let Some(Entry { cost, node, .. }) = queue.pop() {
+        // Skip outdated entries of nodes which were already reached more cheaply
+        if cost > dist[&node] {
+            continue;
+        }
+        if goal(&node) {
+            reached = Some(node);
+            break;
+        }
+        for (next, step) in successors(&node) {
+            let new_cost = cost + step;
+            if dist.get(&next).is_none_or(|&d| new_cost < d) {
+                dist.insert(next.clone(), new_cost);
+                parents.insert(next.clone(), node.clone());
+                queue.push(Entry { priority: new_cost + heuristic(&next), cost: new_cost, node: next });
+            }
+        }
+    }
+
+    (Paths { start, dist, parents }, reached)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Coord;
+    use crate::Grid;
+
+    // Open cells ('.') next to a cell
+    fn open(grid: &Grid<char>, c: &Coord) -> Vec<Coord> {
+        grid.neighbors(c).filter(|(_, _, &v)| v == '.').map(|(n, _, _)| n).collect()
+    }
+
+    // Edges of a small weighted graph, the direct edge from 'a' to 'b' is not the shortest way
+    fn edges(node: &char) -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 10), ('c', 2)],
+            'b' => vec![('d', 1)],
+            'c' => vec![('b', 3), ('d', 9)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn bfs_grid() {
+        let grid: Grid<char> = "..#.\n.#..\n....".parse().unwrap();
+        let start = Coord::from_rc(0, 0);
+        let goal = Coord::from_rc(0, 3);
+        let paths = bfs(start, |c| open(&grid, c));
+
+        assert_eq!(paths.start(), &start);
+        assert_eq!(paths.distance(&goal), Some(7));
+        assert_eq!(paths.distance(&Coord::from_rc(2, 0)), Some(2));
+        assert!(!paths.is_reachable(&Coord::from_rc(0, 2)));
+        assert_eq!(paths.distances().len(), 10);
+
+        let path = paths.path_to(&goal).unwrap();
+        assert_eq!(path.len(), 8);
+        assert_eq!(path.first(), Some(&start));
+        assert_eq!(path.last(), Some(&goal));
+        assert!(path.windows(2).all(|w| w[0].manhattan(&w[1]) == 1));
+        assert_eq!(paths.predecessor(&goal), Some(&Coord::from_rc(1, 3)));
+    }
+
+    #[test]
+    fn dijkstra_weighted() {
+        let paths = dijkstra('a', edges);
+        assert_eq!(paths.distance(&'b'), Some(5));
+        assert_eq!(paths.distance(&'d'), Some(6));
+        assert_eq!(paths.predecessor(&'b'), Some(&'c'));
+        assert_eq!(paths.predecessors().len(), 3);
+        assert_eq!(paths.path_to(&'d'), Some(vec!['a', 'c', 'b', 'd']));
+        assert_eq!(paths.path_to(&'a'), Some(vec!['a']));
+        assert_eq!(reconstruct_path(paths.predecessors(), &'a', &'b'), Some(vec!['a', 'c', 'b']));
+    }
+
+    #[test]
+    fn astar_matches_dijkstra() {
+        let (path, cost) = astar('a', edges, |_| 0, |&n| n == 'd').unwrap();
+        assert_eq!(Some(cost), dijkstra('a', edges).distance(&'d'));
+        assert_eq!(path, vec!['a', 'c', 'b', 'd']);
+
+        let grid: Grid<char> = "..#.\n.#..\n....".parse().unwrap();
+        let goal = Coord::from_rc(0, 3);
+        let successors = |c: &Coord| open(&grid, c).into_iter().map(|n| (n, 1)).collect::<Vec<_>>();
+        let (_, cost) = astar(Coord::ZERO, successors, |c| c.manhattan(&goal) as usize, |&c| c == goal).unwrap();
+        assert_eq!(cost, 7);
+    }
+
+    #[test]
+    fn unreachable_goal() {
+        let grid: Grid<char> = "..#.\n.##.\n..#.".parse().unwrap();
+        let goal = Coord::from_rc(0, 3);
+        let successors = |c: &Coord| open(&grid, c).into_iter().map(|n| (n, 1)).collect::<Vec<_>>();
+        assert_eq!(astar(Coord::ZERO, successors, |c| c.manhattan(&goal) as usize, |&c| c == goal), None);
+
+        let paths = bfs(Coord::ZERO, |c| open(&grid, c));
+        assert_eq!(paths.distance(&goal), None);
+        assert_eq!(paths.path_to(&goal), None);
+        assert_eq!(dijkstra('a', edges).path_to(&'e'), None);
+    }
+}
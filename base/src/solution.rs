@@ -0,0 +1,77 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+// Result of parsing puzzle input, any error can be returned
+pub type ParseResult<T> = Result<T, Box<dyn Error>>;
+
+// Answer for a single part of a puzzle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+macro_rules! answer_from_number {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Answer {
+                Answer::Number(i64::try_from(n).expect("Answer does not fit into i64"))
+            }
+        })*
+    };
+}
+
+answer_from_number!(i8, i16, i32, i64, u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Answer {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Answer {
+        Answer::Text(s.to_string())
+    }
+}
+
+// Puzzle settings which differ between the examples and the real input, set from `key=value` pairs
+pub trait Params: Default {
+    fn set(&mut self, key: &str, value: &str) -> Result<(), String>;
+}
+
+// Days without any settings
+impl Params for () {
+    fn set(&mut self, key: &str, _value: &str) -> Result<(), String> {
+        Err(format!("unknown parameter {:?}", key))
+    }
+}
+
+// Parse the value of a single parameter
+pub fn parse_param<T>(key: &str, value: &str) -> Result<T, String>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    value.parse().map_err(|e| format!("invalid value {:?} of parameter {:?}: {}", value, key, e))
+}
+
+// Common interface of every day, input is parsed once (with the settings) and shared by both parts
+pub trait Solution {
+    type Input;
+    type Params: Params;
+
+    fn parse(input: &str, params: &Self::Params) -> ParseResult<Self::Input>;
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
+}
@@ -0,0 +1,198 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::Coord;
+use crate::GridLike;
+use crate::Rect;
+
+// Unbounded grid storing only the cells which were set, coordinates may be negative
+#[derive(Debug, Clone, Default)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Coord, T>,
+}
+
+// Display the bounding box of all cells, missing cells are shown as dots
+impl<T> fmt::Display for SparseGrid<T>
+where T: fmt::Display {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Some(bounds) = self.bounds() else {
+            return Ok(());
+        };
+        let Coord { row: top, col: left } = bounds.top_left;
+        let width = f.width().unwrap_or(1);
+        for i in top..top + bounds.height {
+            for j in left..left + bounds.width {
+                match self.cells.get(&Coord::from_rc(i, j)) {
+                    Some(cell) => write!(f, "{:1$}", cell, width)?,
+                    None => write!(f, "{:1$}", '.', width)?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> SparseGrid<T> {
+    // Create an empty grid
+    pub fn new() -> SparseGrid<T> {
+        SparseGrid { cells: HashMap::new() }
+    }
+
+    // Get a reference to the value at a coordinate
+    pub fn get(&self, c: &Coord) -> Option<&T> {
+        self.cells.get(c)
+    }
+
+    // Remove a cell, returning its value
+    pub fn remove(&mut self, c: &Coord) -> Option<T> {
+        self.cells.remove(c)
+    }
+
+    pub fn contains(&self, c: &Coord) -> bool {
+        self.cells.contains_key(c)
+    }
+
+    // Number of cells which are set
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    // Iterate over all cells which are set, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (&Coord, &T)> {
+        self.cells.iter()
+    }
+
+    // Smallest rectangle containing all cells, None for an empty grid
+    pub fn bounds(&self) -> Option<Rect> {
+        Rect::bounding(self.cells.keys())
+    }
+}
+
+impl<T> SparseGrid<T>
+where T: PartialEq + Copy {
+    // Get the value at a coordinate
+    pub fn at(&self, c: &Coord) -> Option<T> {
+        self.cells.get(c).copied()
+    }
+
+    // Set the value at a coordinate, the grid grows as needed
+    pub fn set(&mut self, c: Coord, val: T) {
+        self.cells.insert(c, val);
+    }
+
+    // Find all positions of a given value, ordered row by row
+    pub fn find(&self, needle: T) -> Vec<Coord> {
+        let mut result: Vec<Coord> = self.cells.iter().filter(|(_, &v)| v == needle).map(|(&c, _)| c).collect();
+        result.sort();
+        result
+    }
+}
+
+impl<T> GridLike<T> for SparseGrid<T>
+where T: PartialEq + Copy {
+    fn at(&self, c: &Coord) -> Option<T> {
+        SparseGrid::at(self, c)
+    }
+
+    fn set(&mut self, c: Coord, val: T) {
+        SparseGrid::set(self, c, val)
+    }
+
+    fn find(&self, needle: T) -> Vec<Coord> {
+        SparseGrid::find(self, needle)
+    }
+
+    fn bounds(&self) -> Option<Rect> {
+        SparseGrid::bounds(self)
+    }
+}
+
+impl<T> FromIterator<(Coord, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Coord, T)>>(iter: I) -> Self {
+        SparseGrid { cells: iter.into_iter().collect() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Grid;
+    use std::collections::HashSet;
+
+    // Number of open cells ('.') reachable from `start`, works on any kind of grid
+    fn reachable<G: GridLike<char>>(grid: &G, start: Coord) -> usize {
+        let mut seen = HashSet::from([start]);
+        let mut stack = vec![start];
+        while let Some(c) = stack.pop() {
+            for (next, _, v) in grid.neighbors(&c) {
+                if v == '.' && seen.insert(next) {
+                    stack.push(next);
+                }
+            }
+        }
+        seen.len()
+    }
+
+    // Wall off every open cell next to a wall, through the shared interface
+    fn thicken<G: GridLike<char>>(grid: &mut G) {
+        let next_to_walls: Vec<Coord> = grid.find('.').into_iter()
+            .filter(|c| grid.neighbors(c).any(|(_, _, v)| v == '#'))
+            .collect();
+        for c in next_to_walls {
+            grid.set(c, '#');
+        }
+    }
+
+    #[test]
+    fn negative_coordinates() {
+        let mut grid = SparseGrid::new();
+        grid.set(Coord::from_rc(-1, -2), 'a');
+        grid.set(Coord::from_rc(0, 0), 'b');
+        grid.set(Coord::from_rc(1, -1), 'c');
+        assert_eq!(grid.bounds(), Some(Rect::new(Coord::from_rc(-1, -2), 3, 3)));
+        assert_eq!(grid.to_string(), "a..\n..b\n.c.\n");
+
+        assert_eq!(grid.remove(&Coord::from_rc(0, 0)), Some('b'));
+        assert_eq!(grid.bounds(), Some(Rect::new(Coord::from_rc(-1, -2), 2, 3)));
+        assert!(!grid.contains(&Coord::from_rc(0, 0)));
+        assert_eq!(grid.len(), 2);
+        assert_eq!(SparseGrid::<char>::new().bounds(), None);
+        assert_eq!(SparseGrid::<char>::new().to_string(), "");
+    }
+
+    #[test]
+    fn find_is_ordered() {
+        let grid: SparseGrid<char> = [(2, 0), (0, 5), (-3, 1), (0, -1), (2, -4)]
+            .into_iter()
+            .map(|(r, c)| (Coord::from_rc(r, c), 'x'))
+            .chain([(Coord::from_rc(1, 1), 'y')])
+            .collect();
+        let expected = [(-3, 1), (0, -1), (0, 5), (2, -4), (2, 0)].map(|(r, c)| Coord::from_rc(r, c));
+        assert_eq!(grid.find('x'), expected);
+        assert_eq!(grid.find('y'), vec![Coord::from_rc(1, 1)]);
+        assert!(grid.find('z').is_empty());
+    }
+
+    #[test]
+    fn shared_interface() {
+        let mut dense: Grid<char> = "...#\n.#..\n.#.#\n....".parse().unwrap();
+        let mut sparse: SparseGrid<char> = ['.', '#']
+            .into_iter()
+            .flat_map(|k| dense.find(k).into_iter().map(move |c| (c, k)))
+            .collect();
+
+        assert_eq!(reachable(&dense, Coord::ZERO), 12);
+        assert_eq!(reachable(&sparse, Coord::ZERO), 12);
+        assert_eq!(GridLike::bounds(&dense), sparse.bounds());
+
+        thicken(&mut dense);
+        thicken(&mut sparse);
+        assert_eq!(GridLike::find(&dense, '#'), sparse.find('#'));
+        assert_eq!(reachable(&dense, Coord::ZERO), reachable(&sparse, Coord::ZERO));
+    }
+}